@@ -0,0 +1,305 @@
+use std::{
+    error::Error,
+    fs,
+    hint::black_box,
+    path::Path,
+    time::{Duration, Instant},
+};
+
+use aoc_core::Solution;
+use serde::{Deserialize, Serialize};
+
+use crate::table;
+
+// changes of the median below this are noise and never flagged as a regression
+const MIN_REGRESSION: Duration = Duration::from_micros(50);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Stats {
+    pub min_ns: u64,
+    pub median_ns: u64,
+    pub max_ns: u64,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &mut [Duration]) -> Self {
+        assert!(!samples.is_empty(), "at least one sample is needed");
+        samples.sort();
+        let n = samples.len();
+        let median = if n.is_multiple_of(2) {
+            (samples[n / 2 - 1] + samples[n / 2]) / 2
+        } else {
+            samples[n / 2]
+        };
+        Self {
+            min_ns: nanos(samples[0]),
+            median_ns: nanos(median),
+            max_ns: nanos(samples[n - 1]),
+        }
+    }
+}
+
+fn nanos(d: Duration) -> u64 {
+    d.as_nanos().try_into().unwrap_or(u64::MAX)
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DayReport {
+    pub day: u8,
+    pub title: String,
+    pub parse: Stats,
+    pub part_1: Stats,
+    pub part_2: Stats,
+}
+
+impl DayReport {
+    pub fn steps(&self) -> [(&'static str, Stats); 3] {
+        [
+            ("parse", self.parse),
+            ("part 1", self.part_1),
+            ("part 2", self.part_2),
+        ]
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Report {
+    pub iterations: usize,
+    pub days: Vec<DayReport>,
+}
+
+impl Report {
+    pub fn load(path: &Path) -> Result<Self, Box<dyn Error>> {
+        let content = fs::read_to_string(path)?;
+        Ok(serde_json::from_str(&content)?)
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), Box<dyn Error>> {
+        fs::write(path, serde_json::to_string_pretty(self)? + "\n")?;
+        Ok(())
+    }
+}
+
+// one uncounted warm-up run first, so cold caches and lazy setup don't skew the samples
+fn time<T>(iterations: usize, mut f: impl FnMut() -> T) -> Stats {
+    black_box(f());
+    let mut samples: Vec<Duration> = (0..iterations)
+        .map(|_| {
+            let start = Instant::now();
+            black_box(f());
+            start.elapsed()
+        })
+        .collect();
+    Stats::from_samples(&mut samples)
+}
+
+pub fn run<S: Solution>(input: &str, iterations: usize) -> Result<DayReport, Box<dyn Error>> {
+    let parsed = S::parse(input)?;
+    let parse = time(iterations, || S::parse(black_box(input)));
+    let part_1 = time(iterations, || S::part_1(black_box(&parsed)).to_string());
+    let part_2 = time(iterations, || S::part_2(black_box(&parsed)).to_string());
+    Ok(DayReport {
+        day: S::DAY,
+        title: S::TITLE.to_owned(),
+        parse,
+        part_1,
+        part_2,
+    })
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Change {
+    pub day: u8,
+    pub step: &'static str,
+    pub baseline: Stats,
+    pub current: Stats,
+}
+
+impl Change {
+    // relative change of the median, 0.1 is 10% slower
+    pub fn ratio(&self) -> f64 {
+        let baseline = self.baseline.median_ns.max(1) as f64;
+        (self.current.median_ns as f64 - baseline) / baseline
+    }
+
+    pub fn is_regression(&self, threshold: f64) -> bool {
+        let increase = self
+            .current
+            .median_ns
+            .saturating_sub(self.baseline.median_ns);
+        self.ratio() > threshold && increase > nanos(MIN_REGRESSION)
+    }
+}
+
+pub fn compare(current: &Report, baseline: &Report) -> Vec<Change> {
+    current
+        .days
+        .iter()
+        .filter_map(|day| {
+            let old = baseline.days.iter().find(|d| d.day == day.day)?;
+            Some(
+                day.steps()
+                    .into_iter()
+                    .zip(old.steps())
+                    .map(|((step, current), (_, baseline))| Change {
+                        day: day.day,
+                        step,
+                        baseline,
+                        current,
+                    })
+                    .collect::<Vec<_>>(),
+            )
+        })
+        .flatten()
+        .collect()
+}
+
+fn format_ns(ns: u64) -> String {
+    format!("{:.2?}", Duration::from_nanos(ns))
+}
+
+pub fn table(report: &Report, changes: &[Change], threshold: f64) -> String {
+    let mut header = vec!["Day", "Step", "Min", "Median", "Max"];
+    if !changes.is_empty() {
+        header.extend(["Baseline", "Change"]);
+    }
+    let mut rows = vec![header.into_iter().map(String::from).collect::<Vec<_>>()];
+    for day in &report.days {
+        for (step, stats) in day.steps() {
+            let mut row = vec![
+                format!("{:02}", day.day),
+                step.to_owned(),
+                format_ns(stats.min_ns),
+                format_ns(stats.median_ns),
+                format_ns(stats.max_ns),
+            ];
+            if let Some(change) = changes.iter().find(|c| c.day == day.day && c.step == step) {
+                row.push(format_ns(change.baseline.median_ns));
+                let mut text = format!("{:+.1}%", change.ratio() * 100.0);
+                if change.is_regression(threshold) {
+                    text += " REGRESSION";
+                }
+                row.push(text);
+            }
+            rows.push(row);
+        }
+    }
+    table::render(&rows)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stats(median_ns: u64) -> Stats {
+        Stats {
+            min_ns: median_ns / 2,
+            median_ns,
+            max_ns: median_ns * 2,
+        }
+    }
+
+    fn report(day: u8, medians: [u64; 3]) -> Report {
+        Report {
+            iterations: 5,
+            days: vec![DayReport {
+                day,
+                title: "Historian Hysteria".to_owned(),
+                parse: stats(medians[0]),
+                part_1: stats(medians[1]),
+                part_2: stats(medians[2]),
+            }],
+        }
+    }
+
+    #[test]
+    fn test_stats_odd() {
+        let mut samples = [5, 1, 3].map(Duration::from_nanos);
+        let result = Stats::from_samples(&mut samples);
+        assert_eq!(
+            result,
+            Stats {
+                min_ns: 1,
+                median_ns: 3,
+                max_ns: 5
+            }
+        );
+    }
+
+    #[test]
+    fn test_stats_even() {
+        let mut samples = [8, 2, 4, 6].map(Duration::from_nanos);
+        let result = Stats::from_samples(&mut samples);
+        assert_eq!(result.median_ns, 5);
+    }
+
+    #[test]
+    fn test_time_warms_up() {
+        let mut calls = 0;
+        time(3, || calls += 1);
+        assert_eq!(calls, 4);
+    }
+
+    #[test]
+    fn test_run() {
+        let report = run::<puzzle_01::Puzzle01>("3   4\n4   3\n", 3).unwrap();
+        assert_eq!(report.day, 1);
+        for (_, stats) in report.steps() {
+            assert!(stats.min_ns <= stats.median_ns && stats.median_ns <= stats.max_ns);
+        }
+    }
+
+    #[test]
+    fn test_run_parse_error() {
+        assert!(run::<puzzle_01::Puzzle01>("3 x\n", 3).is_err());
+    }
+
+    #[test]
+    fn test_json_round_trip() {
+        let report = report(1, [1_000, 2_000, 3_000]);
+        let json = serde_json::to_string(&report).unwrap();
+        assert!(json.contains("\"median_ns\":2000"));
+        assert_eq!(serde_json::from_str::<Report>(&json).unwrap(), report);
+    }
+
+    #[test]
+    fn test_compare() {
+        let baseline = report(1, [100_000, 100_000, 100_000]);
+        let current = report(1, [105_000, 200_000, 50_000]);
+        let changes = compare(&current, &baseline);
+        assert_eq!(changes.len(), 3);
+        assert!(!changes[0].is_regression(0.1));
+        assert!(changes[1].is_regression(0.1));
+        assert!(!changes[2].is_regression(0.1));
+        assert_eq!(changes[2].ratio(), -0.5);
+    }
+
+    #[test]
+    fn test_compare_ignores_noise() {
+        let baseline = report(1, [1_000, 1_000, 1_000]);
+        let current = report(1, [3_000, 3_000, 3_000]);
+        let changes = compare(&current, &baseline);
+        assert!(changes.iter().all(|c| !c.is_regression(0.1)));
+    }
+
+    #[test]
+    fn test_compare_other_day() {
+        let changes = compare(&report(1, [1, 1, 1]), &report(2, [1, 1, 1]));
+        assert!(changes.is_empty());
+    }
+
+    #[test]
+    fn test_table() {
+        let baseline = report(1, [100_000, 100_000, 100_000]);
+        let current = report(1, [100_000, 200_000, 100_000]);
+        let changes = compare(&current, &baseline);
+        assert_eq!(
+            table(&current, &changes, 0.1),
+            "Day  Step    Min       Median    Max       Baseline  Change
+01   parse   50.00µs   100.00µs  200.00µs  100.00µs  +0.0%
+01   part 1  100.00µs  200.00µs  400.00µs  100.00µs  +100.0% REGRESSION
+01   part 2  50.00µs   100.00µs  200.00µs  100.00µs  +0.0%
+"
+        );
+    }
+}
@@ -0,0 +1,213 @@
+use std::{env, error::Error, fmt::Display, fs::read_to_string, path::PathBuf, time::Duration};
+
+use aoc_core::input::cache_dir;
+
+pub const SESSION_ENV: &str = "AOC_SESSION";
+pub const URL_ENV: &str = "AOC_URL";
+pub const DEFAULT_URL: &str = "https://adventofcode.com";
+const SESSION_FILE: &str = "session";
+const USER_AGENT: &str = concat!(
+    "aoc/",
+    env!("CARGO_PKG_VERSION"),
+    " (advent-of-code runner)"
+);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ClientError {
+    MissingSession,
+    Status {
+        url: String,
+        status: u16,
+        body: String,
+    },
+    Transport {
+        url: String,
+        message: String,
+    },
+}
+
+impl Display for ClientError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::MissingSession => write!(
+                f,
+                "no session token, set ${} or write it to {}",
+                SESSION_ENV,
+                session_path().map_or("the cache directory".to_owned(), |p| p
+                    .display()
+                    .to_string())
+            ),
+            Self::Status { url, status, body } => write!(
+                f,
+                "{} answered with {}: {}",
+                url,
+                status,
+                body.trim().lines().next().unwrap_or_default()
+            ),
+            Self::Transport { url, message } => write!(f, "could not reach {}: {}", url, message),
+        }
+    }
+}
+
+impl Error for ClientError {}
+
+// everything that talks to the Advent of Code website goes through this, so it can be replaced in tests
+pub trait Client {
+    fn get(&self, path: &str) -> Result<String, ClientError>;
+    fn post(&self, path: &str, form: &[(&str, &str)]) -> Result<String, ClientError>;
+}
+
+pub struct HttpClient {
+    base_url: String,
+    session: Option<String>,
+    agent: ureq::Agent,
+}
+
+impl HttpClient {
+    pub fn new(base_url: &str, session: Option<String>) -> Self {
+        Self {
+            base_url: base_url.trim_end_matches('/').to_owned(),
+            session,
+            agent: ureq::AgentBuilder::new()
+                .timeout(Duration::from_secs(30))
+                .user_agent(USER_AGENT)
+                .build(),
+        }
+    }
+
+    // $AOC_URL and the session token from $AOC_SESSION or the session file in the cache directory
+    pub fn from_env() -> Self {
+        let base_url = env::var(URL_ENV).unwrap_or_else(|_| DEFAULT_URL.to_owned());
+        let session = env::var(SESSION_ENV)
+            .ok()
+            .or_else(|| read_to_string(session_path()?).ok())
+            .map(|s| s.trim().to_owned())
+            .filter(|s| !s.is_empty());
+        Self::new(&base_url, session)
+    }
+
+    fn url(&self, path: &str) -> String {
+        format!("{}{}", self.base_url, path)
+    }
+
+    fn session(&self) -> Result<String, ClientError> {
+        self.session
+            .as_ref()
+            .map(|s| format!("session={}", s))
+            .ok_or(ClientError::MissingSession)
+    }
+}
+
+fn session_path() -> Option<PathBuf> {
+    cache_dir().map(|dir| dir.join(SESSION_FILE))
+}
+
+fn into_body(
+    url: String,
+    response: Result<ureq::Response, ureq::Error>,
+) -> Result<String, ClientError> {
+    match response {
+        Ok(response) => response.into_string().map_err(|e| ClientError::Transport {
+            url,
+            message: e.to_string(),
+        }),
+        Err(ureq::Error::Status(status, response)) => Err(ClientError::Status {
+            url,
+            status,
+            body: response.into_string().unwrap_or_default(),
+        }),
+        Err(e) => Err(ClientError::Transport {
+            url,
+            message: e.to_string(),
+        }),
+    }
+}
+
+impl Client for HttpClient {
+    fn get(&self, path: &str) -> Result<String, ClientError> {
+        let url = self.url(path);
+        let response = self.agent.get(&url).set("Cookie", &self.session()?).call();
+        into_body(url, response)
+    }
+
+    fn post(&self, path: &str, form: &[(&str, &str)]) -> Result<String, ClientError> {
+        let url = self.url(path);
+        let response = self
+            .agent
+            .post(&url)
+            .set("Cookie", &self.session()?)
+            .send_form(form);
+        into_body(url, response)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::test_server::TestServer;
+
+    #[test]
+    fn test_get() {
+        let server = TestServer::start(vec![(200, "1 2\n3 4\n")]);
+        let client = HttpClient::new(&server.url, Some("abc".to_owned()));
+        assert_eq!(client.get("/2024/day/1/input"), Ok("1 2\n3 4\n".to_owned()));
+
+        let requests = server.requests();
+        assert_eq!(requests[0].method, "GET");
+        assert_eq!(requests[0].path, "/2024/day/1/input");
+        assert_eq!(requests[0].header("cookie"), Some("session=abc"));
+        assert!(requests[0]
+            .header("user-agent")
+            .unwrap()
+            .starts_with("aoc/"));
+    }
+
+    #[test]
+    fn test_post() {
+        let server = TestServer::start(vec![(200, "<article>ok</article>")]);
+        let client = HttpClient::new(&server.url, Some("abc".to_owned()));
+        let result = client.post("/2024/day/1/answer", &[("level", "1"), ("answer", "11")]);
+        assert_eq!(result, Ok("<article>ok</article>".to_owned()));
+
+        let requests = server.requests();
+        assert_eq!(requests[0].method, "POST");
+        assert_eq!(requests[0].path, "/2024/day/1/answer");
+        assert_eq!(requests[0].body, "level=1&answer=11");
+        assert_eq!(
+            requests[0].header("content-type"),
+            Some("application/x-www-form-urlencoded")
+        );
+        assert_eq!(requests[0].header("cookie"), Some("session=abc"));
+    }
+
+    #[test]
+    fn test_get_status() {
+        let server = TestServer::start(vec![(404, "Not Found\n")]);
+        let client = HttpClient::new(&server.url, Some("abc".to_owned()));
+        assert_eq!(
+            client.get("/2024/day/30/input"),
+            Err(ClientError::Status {
+                url: format!("{}/2024/day/30/input", server.url),
+                status: 404,
+                body: "Not Found\n".to_owned()
+            })
+        );
+    }
+
+    #[test]
+    fn test_get_without_session() {
+        let client = HttpClient::new("http://127.0.0.1:9", None);
+        assert_eq!(client.get("/"), Err(ClientError::MissingSession));
+    }
+
+    #[test]
+    fn test_get_unreachable() {
+        let url = TestServer::unused_url();
+        let client = HttpClient::new(&url, Some("abc".to_owned()));
+        assert!(matches!(
+            client.get("/2024/day/1/input"),
+            Err(ClientError::Transport { .. })
+        ));
+    }
+}
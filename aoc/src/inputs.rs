@@ -0,0 +1,218 @@
+use std::{
+    error::Error,
+    fmt::Display,
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use aoc_core::input::cache_path_in;
+
+use crate::client::{Client, ClientError};
+
+#[derive(Debug)]
+pub enum InputError {
+    NoCacheDir,
+    Io { path: PathBuf, source: io::Error },
+    Client(ClientError),
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NoCacheDir => write!(
+                f,
+                "no cache directory, set $AOC_CACHE_DIR, $XDG_CACHE_HOME or $HOME"
+            ),
+            Self::Io { path, source } => write!(f, "{}: {}", path.display(), source),
+            Self::Client(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl Error for InputError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Io { source, .. } => Some(source),
+            Self::Client(e) => Some(e),
+            Self::NoCacheDir => None,
+        }
+    }
+}
+
+impl From<ClientError> for InputError {
+    fn from(e: ClientError) -> Self {
+        Self::Client(e)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Fetch {
+    Cached,
+    Downloaded,
+}
+
+// keeps the inputs in the cache directory by year and day, only goes online for missing ones
+pub struct InputManager<C: Client> {
+    cache_dir: PathBuf,
+    year: u16,
+    client: C,
+}
+
+impl<C: Client> InputManager<C> {
+    pub fn new(cache_dir: &Path, year: u16, client: C) -> Self {
+        Self {
+            cache_dir: cache_dir.to_path_buf(),
+            year,
+            client,
+        }
+    }
+
+    pub fn path(&self, day: u8) -> PathBuf {
+        cache_path_in(&self.cache_dir, self.year, day)
+    }
+
+    pub fn ensure(&self, day: u8) -> Result<Fetch, InputError> {
+        let path = self.path(day);
+        if path.is_file() {
+            return Ok(Fetch::Cached);
+        }
+        let content = self
+            .client
+            .get(&format!("/{}/day/{}/input", self.year, day))?;
+        store(&path, &content)?;
+        Ok(Fetch::Downloaded)
+    }
+}
+
+// written next to the target first, so an interrupted download never looks cached
+fn store(path: &Path, content: &str) -> Result<(), InputError> {
+    let io_error = |source| InputError::Io {
+        path: path.to_path_buf(),
+        source,
+    };
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).map_err(io_error)?;
+    }
+    let partial = path.with_extension("part");
+    fs::write(&partial, content).map_err(io_error)?;
+    fs::rename(&partial, path).map_err(io_error)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::RefCell, env};
+
+    use super::*;
+
+    use crate::{client::HttpClient, test_server::TestServer};
+
+    struct FakeClient {
+        response: Result<String, ClientError>,
+        requests: RefCell<Vec<String>>,
+    }
+
+    impl FakeClient {
+        fn new(response: Result<&str, ClientError>) -> Self {
+            Self {
+                response: response.map(str::to_owned),
+                requests: RefCell::new(vec![]),
+            }
+        }
+    }
+
+    impl Client for FakeClient {
+        fn get(&self, path: &str) -> Result<String, ClientError> {
+            self.requests.borrow_mut().push(path.to_owned());
+            self.response.clone()
+        }
+
+        fn post(&self, path: &str, _: &[(&str, &str)]) -> Result<String, ClientError> {
+            self.get(path)
+        }
+    }
+
+    fn get<C: Client>(manager: &InputManager<C>, day: u8) -> Result<String, InputError> {
+        manager.ensure(day)?;
+        Ok(fs::read_to_string(manager.path(day)).unwrap())
+    }
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("aoc_inputs_{}_{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[test]
+    fn test_fetch_and_cache() {
+        let dir = temp_dir("fetch");
+        let manager = InputManager::new(&dir, 2024, FakeClient::new(Ok("125 17\n")));
+
+        assert_eq!(manager.ensure(11).unwrap(), Fetch::Downloaded);
+        assert_eq!(get(&manager, 11).unwrap(), "125 17\n");
+        assert_eq!(manager.path(11), dir.join("2024").join("11.txt"));
+        assert_eq!(
+            *manager.client.requests.borrow(),
+            vec!["/2024/day/11/input"]
+        );
+        assert!(!dir.join("2024").join("11.part").exists());
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_offline_from_cache() {
+        let dir = temp_dir("offline");
+        fs::create_dir_all(dir.join("2024")).unwrap();
+        fs::write(dir.join("2024").join("03.txt"), "mul(2,4)").unwrap();
+        let offline = ClientError::Transport {
+            url: "http://offline".to_owned(),
+            message: "no network".to_owned(),
+        };
+        let manager = InputManager::new(&dir, 2024, FakeClient::new(Err(offline.clone())));
+
+        assert_eq!(manager.ensure(3).unwrap(), Fetch::Cached);
+        assert_eq!(get(&manager, 3).unwrap(), "mul(2,4)");
+        assert!(manager.client.requests.borrow().is_empty());
+
+        let result = get(&manager, 4);
+        assert!(matches!(result, Err(InputError::Client(e)) if e == offline));
+        assert!(!manager.path(4).exists());
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_fetch_over_http() {
+        let dir = temp_dir("http");
+        let server = TestServer::start(vec![(200, "3   4\n4   3\n")]);
+        let client = HttpClient::new(&server.url, Some("secret".to_owned()));
+        let manager = InputManager::new(&dir, 2024, client);
+
+        assert_eq!(get(&manager, 1).unwrap(), "3   4\n4   3\n");
+        // served from the cache, the server only answers once
+        assert_eq!(get(&manager, 1).unwrap(), "3   4\n4   3\n");
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].path, "/2024/day/1/input");
+        assert_eq!(requests[0].header("cookie"), Some("session=secret"));
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_fetch_over_http_error_is_not_cached() {
+        let dir = temp_dir("http_error");
+        let server = TestServer::start(vec![(400, "Puzzle inputs differ by user.")]);
+        let client = HttpClient::new(&server.url, Some("expired".to_owned()));
+        let manager = InputManager::new(&dir, 2024, client);
+
+        let result = manager.ensure(1);
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            format!(
+                "{}/2024/day/1/input answered with 400: Puzzle inputs differ by user.",
+                server.url
+            )
+        );
+        assert!(!manager.path(1).exists());
+        server.requests();
+    }
+}
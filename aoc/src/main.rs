@@ -0,0 +1,437 @@
+use std::{
+    io::{self, IsTerminal},
+    path::PathBuf,
+    process::ExitCode,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use aoc_core::{
+    answers::{self, Answers},
+    input::{cache_dir, InputSource},
+    Part, YEAR,
+};
+use bench::Report;
+use clap::{Parser, Subcommand};
+use client::HttpClient;
+use inputs::{Fetch, InputError, InputManager};
+use solutions::{Entry, SOLUTIONS};
+use submit::{History, Outcome, Submitter};
+
+mod bench;
+mod client;
+mod inputs;
+mod solutions;
+mod submit;
+mod table;
+#[cfg(test)]
+mod test_server;
+mod verify;
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Run the Advent of Code solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run the solution of one day or of all days
+    Run {
+        /// Day to run, or "all"
+        day: DaySelection,
+        /// Only run this part
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        /// Input file ("-" for stdin, "{day}" is replaced by the day),
+        /// defaults to $AOC_INPUT or puzzle_NN/input.txt
+        #[arg(long, short)]
+        input: Option<String>,
+        /// Also print the day's debug picture, colored when stdout is a terminal
+        #[arg(long)]
+        debug: bool,
+    },
+    /// Check the answers of one day or of all days against the answers file
+    Verify {
+        /// Day to check, or "all"
+        #[arg(default_value = "all")]
+        day: DaySelection,
+        /// Only check this part
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        /// Input file, same as for run
+        #[arg(long, short)]
+        input: Option<String>,
+        /// Answers file, defaults to answers.txt in the workspace
+        #[arg(long)]
+        answers: Option<PathBuf>,
+    },
+    /// Download missing inputs into the cache directory
+    Fetch {
+        /// Day to download, or "all"
+        #[arg(default_value = "all")]
+        day: DaySelection,
+    },
+    /// Time parse, part 1 and part 2 of one day or of all days
+    Bench {
+        /// Day to time, or "all"
+        #[arg(default_value = "all")]
+        day: DaySelection,
+        /// Number of timed runs of every step
+        #[arg(long, short = 'n', default_value_t = 10,
+              value_parser = clap::value_parser!(u32).range(1..))]
+        iterations: u32,
+        /// Input file, same as for run
+        #[arg(long, short)]
+        input: Option<String>,
+        /// Write the report as JSON to this file
+        #[arg(long, short)]
+        output: Option<PathBuf>,
+        /// Compare against a report written by an earlier run
+        #[arg(long)]
+        baseline: Option<PathBuf>,
+        /// Flag steps whose median got slower by more than this many percent
+        #[arg(long, default_value_t = 10.0)]
+        threshold: f64,
+    },
+    /// Submit the answer of one part, refuses answers that were already rejected
+    Submit {
+        /// Day to submit
+        day: DaySelection,
+        /// Part to submit
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+        /// Answer to submit, computed by the solution if not given
+        answer: Option<String>,
+        /// Input file to compute the answer from, same as for run
+        #[arg(long, short)]
+        input: Option<String>,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum DaySelection {
+    All,
+    Day(u8),
+}
+
+impl std::str::FromStr for DaySelection {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.eq_ignore_ascii_case("all") {
+            return Ok(Self::All);
+        }
+        let day = s
+            .parse::<u8>()
+            .map_err(|_| format!("expected a day number or \"all\", got \"{}\"", s))?;
+        match solutions::get(day) {
+            Some(_) => Ok(Self::Day(day)),
+            None => Err(format!("no solution for day {}", day)),
+        }
+    }
+}
+
+impl DaySelection {
+    fn entries(&self) -> Vec<&'static Entry> {
+        match self {
+            Self::All => SOLUTIONS.iter().collect(),
+            Self::Day(day) => solutions::get(*day).into_iter().collect(),
+        }
+    }
+}
+
+fn run(entry: &Entry, parts: &[Part], input: Option<&str>, debug: bool) -> bool {
+    println!("Day {:02}: {}", entry.day, entry.title);
+    let source = InputSource::resolve(entry.day, input);
+    let input = match source.read() {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!("  Could not read {}: {}", source, e);
+            return false;
+        }
+    };
+    match (entry.solve)(parts, &input) {
+        Ok(answers) => {
+            for (part, answer) in parts.iter().zip(answers) {
+                println!("  Part {}: {}", part, answer);
+            }
+        }
+        Err(e) => {
+            eprintln!("  Could not parse {}: {}", source, e);
+            return false;
+        }
+    }
+    if debug {
+        match (entry.debug)(&input, io::stdout().is_terminal()) {
+            Ok(Some(picture)) => println!("{}", picture),
+            Ok(None) => println!("  No debug output"),
+            Err(e) => {
+                eprintln!("  Could not parse {}: {}", source, e);
+                return false;
+            }
+        }
+    }
+    true
+}
+
+fn selected_parts(part: Option<u8>) -> Vec<Part> {
+    match part.and_then(Part::from_number) {
+        Some(part) => vec![part],
+        None => Part::variants(),
+    }
+}
+
+fn verify_all(
+    day: DaySelection,
+    parts: &[Part],
+    input: Option<&str>,
+    answers_path: Option<PathBuf>,
+) -> bool {
+    let answers_path = answers_path.unwrap_or_else(answers::default_path);
+    let answers = match Answers::load(&answers_path) {
+        Ok(answers) => answers,
+        Err(e) => {
+            eprintln!("Could not load the answers: {}", e);
+            return false;
+        }
+    };
+    let rows: Vec<_> = day
+        .entries()
+        .into_iter()
+        .flat_map(|entry| {
+            let source = InputSource::resolve(entry.day, input);
+            verify::verify(entry, parts, &source, &answers)
+        })
+        .collect();
+    print!("{}", verify::table(&rows));
+    println!();
+    println!("{}", verify::summary(&rows));
+    !rows.iter().any(|row| row.status.is_failure())
+}
+
+fn fetch_all(day: DaySelection) -> bool {
+    let Some(cache_dir) = cache_dir() else {
+        eprintln!("{}", InputError::NoCacheDir);
+        return false;
+    };
+    let manager = InputManager::new(&cache_dir, YEAR, HttpClient::from_env());
+    let mut success = true;
+    for entry in day.entries() {
+        match manager.ensure(entry.day) {
+            Ok(Fetch::Cached) => println!("Day {:02}: already cached", entry.day),
+            Ok(Fetch::Downloaded) => println!(
+                "Day {:02}: downloaded to {}",
+                entry.day,
+                manager.path(entry.day).display()
+            ),
+            Err(e) => {
+                eprintln!("Day {:02}: {}", entry.day, e);
+                success = false;
+            }
+        }
+    }
+    success
+}
+
+fn bench_all(
+    day: DaySelection,
+    iterations: usize,
+    input: Option<&str>,
+    output: Option<PathBuf>,
+    baseline: Option<PathBuf>,
+    threshold: f64,
+) -> bool {
+    let baseline = match baseline.map(|path| Report::load(&path).map_err(|e| (path, e))) {
+        Some(Err((path, e))) => {
+            eprintln!("Could not load the baseline {}: {}", path.display(), e);
+            return false;
+        }
+        Some(Ok(report)) => Some(report),
+        None => None,
+    };
+    let mut success = true;
+    let mut report = Report {
+        iterations,
+        days: vec![],
+    };
+    for entry in day.entries() {
+        let source = InputSource::resolve(entry.day, input);
+        let content = match source.read() {
+            Ok(content) => content,
+            Err(e) => {
+                eprintln!("Day {:02}: could not read {}: {}", entry.day, source, e);
+                success = false;
+                continue;
+            }
+        };
+        match (entry.bench)(&content, iterations) {
+            Ok(day_report) => report.days.push(day_report),
+            Err(e) => {
+                eprintln!("Day {:02}: could not parse {}: {}", entry.day, source, e);
+                success = false;
+            }
+        }
+    }
+    let changes = baseline
+        .as_ref()
+        .map(|baseline| bench::compare(&report, baseline))
+        .unwrap_or_default();
+    let threshold = threshold / 100.0;
+    print!("{}", bench::table(&report, &changes, threshold));
+    let regressions = changes
+        .iter()
+        .filter(|c| c.is_regression(threshold))
+        .count();
+    if baseline.is_some() {
+        println!();
+        println!("{} regressions", regressions);
+    }
+    if let Some(path) = output {
+        if let Err(e) = report.save(&path) {
+            eprintln!("Could not write {}: {}", path.display(), e);
+            success = false;
+        }
+    }
+    success && regressions == 0
+}
+
+fn submit_answer(entry: &Entry, part: Part, answer: Option<String>, input: Option<&str>) -> bool {
+    let Some(cache_dir) = cache_dir() else {
+        eprintln!("{}", InputError::NoCacheDir);
+        return false;
+    };
+    let answer = match answer {
+        Some(answer) => answer,
+        None => {
+            let source = InputSource::resolve(entry.day, input);
+            let result = source
+                .read()
+                .map_err(|e| format!("could not read {}: {}", source, e))
+                .and_then(|content| {
+                    (entry.solve)(&[part], &content)
+                        .map(|mut answers| answers.remove(0))
+                        .map_err(|e| format!("could not parse {}: {}", source, e))
+                });
+            match result {
+                Ok(answer) => answer,
+                Err(e) => {
+                    eprintln!("Day {:02}: {}", entry.day, e);
+                    return false;
+                }
+            }
+        }
+    };
+    println!("Day {:02} part {}: submitting {}", entry.day, part, answer);
+
+    let history_path = History::path(&cache_dir, YEAR);
+    let mut history = match History::load(&history_path) {
+        Ok(history) => history,
+        Err(e) => {
+            eprintln!("Could not load the submissions: {}", e);
+            return false;
+        }
+    };
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs());
+    let submitter = Submitter::new(YEAR, HttpClient::from_env());
+    let outcome = submitter.submit(&mut history, entry.day, part, &answer, now);
+    if let Err(e) = history.save(&history_path) {
+        eprintln!("Could not save the submissions: {}", e);
+    }
+    match outcome {
+        Ok(outcome) => {
+            println!("{}", outcome);
+            outcome == Outcome::Correct
+        }
+        Err(e) => {
+            eprintln!("{}", e);
+            false
+        }
+    }
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    let success = match cli.command {
+        Command::Run {
+            day,
+            part,
+            input,
+            debug,
+        } => {
+            let parts = selected_parts(part);
+            let mut success = true;
+            for (i, entry) in day.entries().into_iter().enumerate() {
+                if i > 0 {
+                    println!();
+                }
+                success &= run(entry, &parts, input.as_deref(), debug);
+            }
+            success
+        }
+        Command::Verify {
+            day,
+            part,
+            input,
+            answers,
+        } => verify_all(day, &selected_parts(part), input.as_deref(), answers),
+        Command::Fetch { day } => fetch_all(day),
+        Command::Bench {
+            day,
+            iterations,
+            input,
+            output,
+            baseline,
+            threshold,
+        } => bench_all(
+            day,
+            iterations as usize,
+            input.as_deref(),
+            output,
+            baseline,
+            threshold,
+        ),
+        Command::Submit {
+            day,
+            part,
+            answer,
+            input,
+        } => match (day, Part::from_number(part)) {
+            (DaySelection::Day(day), Some(part)) => {
+                let entry = solutions::get(day).expect("days are checked when parsed");
+                submit_answer(entry, part, answer, input.as_deref())
+            }
+            _ => {
+                eprintln!("Submit needs a single day and part");
+                false
+            }
+        },
+    };
+    if success {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_day_selection() {
+        assert_eq!("all".parse(), Ok(DaySelection::All));
+        assert_eq!("7".parse(), Ok(DaySelection::Day(7)));
+        assert!("0".parse::<DaySelection>().is_err());
+        assert!("seven".parse::<DaySelection>().is_err());
+    }
+
+    #[test]
+    fn test_entries() {
+        assert_eq!(DaySelection::All.entries().len(), 14);
+        assert_eq!(DaySelection::Day(3).entries()[0].day, 3);
+    }
+}
@@ -0,0 +1,78 @@
+use std::error::Error;
+
+use aoc_core::{Part, Solution};
+
+use crate::bench::{self, DayReport};
+
+pub type Solver = fn(&[Part], &str) -> Result<Vec<String>, Box<dyn Error>>;
+pub type Bencher = fn(&str, usize) -> Result<DayReport, Box<dyn Error>>;
+pub type Debugger = fn(&str, bool) -> Result<Option<String>, Box<dyn Error>>;
+
+pub struct Entry {
+    pub day: u8,
+    pub title: &'static str,
+    pub solve: Solver,
+    pub bench: Bencher,
+    pub debug: Debugger,
+}
+
+const fn entry<S: Solution>() -> Entry {
+    Entry {
+        day: S::DAY,
+        title: S::TITLE,
+        solve: S::solve,
+        bench: bench::run::<S>,
+        debug: debug::<S>,
+    }
+}
+
+fn debug<S: Solution>(input: &str, color: bool) -> Result<Option<String>, Box<dyn Error>> {
+    Ok(S::debug(&S::parse(input)?, color))
+}
+
+pub const SOLUTIONS: [Entry; 14] = [
+    entry::<puzzle_01::Puzzle01>(),
+    entry::<puzzle_02::Puzzle02>(),
+    entry::<puzzle_03::Puzzle03>(),
+    entry::<puzzle_04::Puzzle04>(),
+    entry::<puzzle_05::Puzzle05>(),
+    entry::<puzzle_06::Puzzle06>(),
+    entry::<puzzle_07::Puzzle07>(),
+    entry::<puzzle_08::Puzzle08>(),
+    entry::<puzzle_09::Puzzle09>(),
+    entry::<puzzle_10::Puzzle10>(),
+    entry::<puzzle_11::Puzzle11>(),
+    entry::<puzzle_12::Puzzle12>(),
+    entry::<puzzle_13::Puzzle13>(),
+    entry::<puzzle_14::Puzzle14>(),
+];
+
+pub fn get(day: u8) -> Option<&'static Entry> {
+    SOLUTIONS.iter().find(|e| e.day == day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_days_are_unique_and_ordered() {
+        let days: Vec<u8> = SOLUTIONS.iter().map(|e| e.day).collect();
+        assert_eq!(days, (1..=14).collect::<Vec<u8>>());
+    }
+
+    #[test]
+    fn test_get() {
+        assert_eq!(get(7).map(|e| e.title), Some("Bridge Repair"));
+        assert!(get(25).is_none());
+    }
+
+    #[test]
+    fn test_debug() {
+        let lab = include_str!("../../puzzle_06/fixtures/example.txt");
+        let picture = (get(6).unwrap().debug)(lab, false).unwrap().unwrap();
+        assert_eq!(picture.matches('X').count(), 41);
+        assert_eq!((get(7).unwrap().debug)("1: 1", false).unwrap(), None);
+        assert!((get(6).unwrap().debug)("", false).is_err());
+    }
+}
@@ -0,0 +1,577 @@
+use std::{
+    error::Error,
+    fmt::Display,
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use aoc_core::Part;
+use serde::{Deserialize, Serialize};
+
+use crate::client::{Client, ClientError};
+
+const HISTORY_FILE: &str = "submissions.json";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Hint {
+    TooHigh,
+    TooLow,
+}
+
+impl Display for Hint {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::TooHigh => write!(f, "too high"),
+            Self::TooLow => write!(f, "too low"),
+        }
+    }
+}
+
+// what the website answered to a submission
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    Correct,
+    Wrong {
+        hint: Option<Hint>,
+        wait: Option<u64>,
+    },
+    TooSoon {
+        wait: Option<u64>,
+    },
+    AlreadySolved,
+    Unknown(String),
+}
+
+impl Display for Outcome {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Correct => write!(f, "That's the right answer!"),
+            Self::Wrong { hint, wait } => {
+                write!(f, "That's not the right answer")?;
+                if let Some(hint) = hint {
+                    write!(f, ", it is {}", hint)?;
+                }
+                if let Some(wait) = wait {
+                    write!(f, ", wait {}s before trying again", wait)?;
+                }
+                Ok(())
+            }
+            Self::TooSoon { wait: Some(wait) } => {
+                write!(f, "Answered too recently, wait {}s", wait)
+            }
+            Self::TooSoon { wait: None } => write!(f, "Answered too recently"),
+            Self::AlreadySolved => write!(f, "This part is already solved"),
+            Self::Unknown(text) => write!(f, "Unexpected response: {}", text),
+        }
+    }
+}
+
+pub fn parse_response(html: &str) -> Outcome {
+    let text = article_text(html);
+    if text.contains("That's the right answer") {
+        Outcome::Correct
+    } else if text.contains("That's not the right answer") {
+        let hint = if text.contains("too high") {
+            Some(Hint::TooHigh)
+        } else if text.contains("too low") {
+            Some(Hint::TooLow)
+        } else {
+            None
+        };
+        Outcome::Wrong {
+            hint,
+            wait: parse_wait(&text),
+        }
+    } else if text.contains("You gave an answer too recently") {
+        Outcome::TooSoon {
+            wait: parse_wait(&text),
+        }
+    } else if text.contains("You don't seem to be solving the right level") {
+        Outcome::AlreadySolved
+    } else {
+        Outcome::Unknown(text)
+    }
+}
+
+// the text inside <article>, or the whole page, without tags and with collapsed whitespace
+fn article_text(html: &str) -> String {
+    let article = html
+        .split_once("<article")
+        .and_then(|(_, rest)| rest.split_once('>'))
+        .map(|(_, rest)| rest.split("</article>").next().unwrap_or_default())
+        .unwrap_or(html);
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in article.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+// "You have 1m 5s left to wait" or "please wait one minute before trying again"
+fn parse_wait(text: &str) -> Option<u64> {
+    if let Some((_, rest)) = text.split_once("You have ") {
+        let (left, _) = rest.split_once(" left to wait")?;
+        return left
+            .split_whitespace()
+            .map(|token| {
+                let (value, unit) = token.split_at(token.find(|c: char| !c.is_ascii_digit())?);
+                let value: u64 = value.parse().ok()?;
+                match unit {
+                    "h" => Some(value * 3600),
+                    "m" => Some(value * 60),
+                    "s" => Some(value),
+                    _ => None,
+                }
+            })
+            .sum();
+    }
+    let (_, rest) = text.split_once("wait ")?;
+    let mut words = rest.split_whitespace();
+    let value = match words.next()? {
+        "one" | "a" => 1,
+        n => n.parse().ok()?,
+    };
+    match words.next()?.trim_end_matches('s') {
+        "minute" => Some(value * 60),
+        "second" => Some(value),
+        _ => None,
+    }
+}
+
+// why an answer is not sent at all
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Refusal {
+    AlreadyRejected { hint: Option<Hint> },
+    OutOfRange { hint: Hint, previous: String },
+    AlreadyAccepted { answer: String },
+    RateLimited { wait: u64 },
+}
+
+impl Display for Refusal {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::AlreadyRejected { hint: Some(hint) } => {
+                write!(f, "this answer was already rejected as {}", hint)
+            }
+            Self::AlreadyRejected { hint: None } => write!(f, "this answer was already rejected"),
+            Self::OutOfRange { hint, previous } => {
+                write!(f, "{} was already rejected as {}", previous, hint)
+            }
+            Self::AlreadyAccepted { answer } => {
+                write!(f, "this part is already solved with {}", answer)
+            }
+            Self::RateLimited { wait } => write!(f, "wait {}s before submitting again", wait),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Verdict {
+    Correct,
+    Wrong,
+    TooHigh,
+    TooLow,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Submission {
+    pub day: u8,
+    pub part: u8,
+    pub answer: String,
+    pub verdict: Verdict,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct History {
+    // unix seconds before which the website does not accept answers
+    #[serde(default)]
+    pub wait_until: u64,
+    #[serde(default)]
+    pub submissions: Vec<Submission>,
+}
+
+impl History {
+    pub fn path(cache_dir: &Path, year: u16) -> PathBuf {
+        cache_dir.join(year.to_string()).join(HISTORY_FILE)
+    }
+
+    pub fn load(path: &Path) -> Result<Self, SubmitError> {
+        match fs::read_to_string(path) {
+            Ok(content) => serde_json::from_str(&content).map_err(|e| SubmitError::History {
+                path: path.to_path_buf(),
+                message: e.to_string(),
+            }),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(SubmitError::History {
+                path: path.to_path_buf(),
+                message: e.to_string(),
+            }),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), SubmitError> {
+        let error = |message: String| SubmitError::History {
+            path: path.to_path_buf(),
+            message,
+        };
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir).map_err(|e| error(e.to_string()))?;
+        }
+        let json = serde_json::to_string_pretty(self).map_err(|e| error(e.to_string()))?;
+        fs::write(path, json + "\n").map_err(|e| error(e.to_string()))
+    }
+
+    fn of(&self, day: u8, part: Part) -> impl Iterator<Item = &Submission> + Clone {
+        let part = part_number(part);
+        self.submissions
+            .iter()
+            .filter(move |s| s.day == day && s.part == part)
+    }
+
+    pub fn check(&self, day: u8, part: Part, answer: &str, now: u64) -> Result<(), Refusal> {
+        let mut previous = self.of(day, part);
+        if let Some(correct) = previous.clone().find(|s| s.verdict == Verdict::Correct) {
+            return Err(Refusal::AlreadyAccepted {
+                answer: correct.answer.clone(),
+            });
+        }
+        if let Some(rejected) = previous.clone().find(|s| s.answer == answer) {
+            return Err(Refusal::AlreadyRejected {
+                hint: hint(rejected.verdict),
+            });
+        }
+        if let Ok(value) = answer.parse::<i128>() {
+            let out_of_range = previous.find(|s| match (s.verdict, s.answer.parse::<i128>()) {
+                (Verdict::TooHigh, Ok(high)) => value >= high,
+                (Verdict::TooLow, Ok(low)) => value <= low,
+                _ => false,
+            });
+            if let Some(s) = out_of_range {
+                return Err(Refusal::OutOfRange {
+                    hint: hint(s.verdict).expect("only high or low bound answers"),
+                    previous: s.answer.clone(),
+                });
+            }
+        }
+        if now < self.wait_until {
+            return Err(Refusal::RateLimited {
+                wait: self.wait_until - now,
+            });
+        }
+        Ok(())
+    }
+
+    pub fn record(&mut self, day: u8, part: Part, answer: &str, outcome: &Outcome, now: u64) {
+        let verdict = match outcome {
+            Outcome::Correct => Some(Verdict::Correct),
+            Outcome::Wrong { hint, .. } => Some(match hint {
+                Some(Hint::TooHigh) => Verdict::TooHigh,
+                Some(Hint::TooLow) => Verdict::TooLow,
+                None => Verdict::Wrong,
+            }),
+            _ => None,
+        };
+        if let Some(verdict) = verdict {
+            self.submissions.push(Submission {
+                day,
+                part: part_number(part),
+                answer: answer.to_owned(),
+                verdict,
+            });
+        }
+        if let Outcome::Wrong {
+            wait: Some(wait), ..
+        }
+        | Outcome::TooSoon { wait: Some(wait) } = outcome
+        {
+            self.wait_until = now + wait;
+        }
+    }
+}
+
+fn hint(verdict: Verdict) -> Option<Hint> {
+    match verdict {
+        Verdict::TooHigh => Some(Hint::TooHigh),
+        Verdict::TooLow => Some(Hint::TooLow),
+        Verdict::Correct | Verdict::Wrong => None,
+    }
+}
+
+fn part_number(part: Part) -> u8 {
+    match part {
+        Part::One => 1,
+        Part::Two => 2,
+    }
+}
+
+#[derive(Debug)]
+pub enum SubmitError {
+    Refused(Refusal),
+    Client(ClientError),
+    History { path: PathBuf, message: String },
+}
+
+impl Display for SubmitError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Refused(refusal) => write!(f, "not submitted, {}", refusal),
+            Self::Client(e) => write!(f, "{}", e),
+            Self::History { path, message } => write!(f, "{}: {}", path.display(), message),
+        }
+    }
+}
+
+impl Error for SubmitError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Client(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<ClientError> for SubmitError {
+    fn from(e: ClientError) -> Self {
+        Self::Client(e)
+    }
+}
+
+pub struct Submitter<C: Client> {
+    year: u16,
+    client: C,
+}
+
+impl<C: Client> Submitter<C> {
+    pub fn new(year: u16, client: C) -> Self {
+        Self { year, client }
+    }
+
+    pub fn submit(
+        &self,
+        history: &mut History,
+        day: u8,
+        part: Part,
+        answer: &str,
+        now: u64,
+    ) -> Result<Outcome, SubmitError> {
+        history
+            .check(day, part, answer, now)
+            .map_err(SubmitError::Refused)?;
+        let level = part.to_string();
+        let body = self.client.post(
+            &format!("/{}/day/{}/answer", self.year, day),
+            &[("level", &level), ("answer", answer)],
+        )?;
+        let outcome = parse_response(&body);
+        history.record(day, part, answer, &outcome, now);
+        Ok(outcome)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+
+    use super::*;
+
+    use crate::{client::HttpClient, test_server::TestServer};
+
+    const CORRECT: &str = "<main>\n<article><p>That's the right answer!  You are <em>one gold star</em> closer to finding the Chief Historian. <a href=\"/2024/day/1#part2\">[Continue to Part Two]</a></p></article>\n</main>";
+    const TOO_HIGH: &str = "<article><p>That's not the right answer; your answer is too high.  If you're stuck, make sure you're using the full input data; there are also some general tips on the <a href=\"/2024/about\">about page</a>, or you can ask for hints on the <a href=\"https://www.reddit.com/r/adventofcode/\" target=\"_blank\">subreddit</a>.  Please wait one minute before trying again. <a href=\"/2024/day/1\">[Return to Day 1]</a></p></article>";
+    const TOO_SOON: &str = "<article><p>You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 5s left to wait. <a href=\"/2024/day/1\">[Return to Day 1]</a></p></article>";
+    const SOLVED: &str = "<article><p>You don't seem to be solving the right level.  Did you already complete it? <a href=\"/2024/day/1\">[Return to Day 1]</a></p></article>";
+
+    #[test]
+    fn test_parse_correct() {
+        assert_eq!(parse_response(CORRECT), Outcome::Correct);
+    }
+
+    #[test]
+    fn test_parse_too_high() {
+        assert_eq!(
+            parse_response(TOO_HIGH),
+            Outcome::Wrong {
+                hint: Some(Hint::TooHigh),
+                wait: Some(60)
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_wrong_without_hint() {
+        let html = "<article><p>That's not the right answer.  Please wait 5 minutes before trying again.</p></article>";
+        assert_eq!(
+            parse_response(html),
+            Outcome::Wrong {
+                hint: None,
+                wait: Some(300)
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_too_soon() {
+        assert_eq!(
+            parse_response(TOO_SOON),
+            Outcome::TooSoon { wait: Some(65) }
+        );
+    }
+
+    #[test]
+    fn test_parse_already_solved() {
+        assert_eq!(parse_response(SOLVED), Outcome::AlreadySolved);
+    }
+
+    #[test]
+    fn test_parse_unknown() {
+        assert_eq!(
+            parse_response("<html><body><p>Maintenance</p></body></html>"),
+            Outcome::Unknown("Maintenance".to_owned())
+        );
+    }
+
+    fn history(submissions: &[(u8, &str, Verdict)]) -> History {
+        History {
+            wait_until: 0,
+            submissions: submissions
+                .iter()
+                .map(|&(part, answer, verdict)| Submission {
+                    day: 1,
+                    part,
+                    answer: answer.to_owned(),
+                    verdict,
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn test_check_rejected_again() {
+        let history = history(&[(1, "12", Verdict::Wrong)]);
+        assert_eq!(
+            history.check(1, Part::One, "12", 0),
+            Err(Refusal::AlreadyRejected { hint: None })
+        );
+        assert_eq!(history.check(1, Part::Two, "12", 0), Ok(()));
+        assert_eq!(history.check(2, Part::One, "12", 0), Ok(()));
+    }
+
+    #[test]
+    fn test_check_bounds() {
+        let history = history(&[(1, "100", Verdict::TooHigh), (1, "10", Verdict::TooLow)]);
+        assert_eq!(
+            history.check(1, Part::One, "150", 0),
+            Err(Refusal::OutOfRange {
+                hint: Hint::TooHigh,
+                previous: "100".to_owned()
+            })
+        );
+        assert_eq!(
+            history
+                .check(1, Part::One, "10", 0)
+                .unwrap_err()
+                .to_string(),
+            "this answer was already rejected as too low"
+        );
+        assert_eq!(
+            history
+                .check(1, Part::One, "-5", 0)
+                .unwrap_err()
+                .to_string(),
+            "10 was already rejected as too low"
+        );
+        assert_eq!(history.check(1, Part::One, "50", 0), Ok(()));
+    }
+
+    #[test]
+    fn test_check_solved() {
+        let history = history(&[(2, "7", Verdict::Wrong), (2, "31", Verdict::Correct)]);
+        assert_eq!(
+            history.check(1, Part::Two, "32", 0),
+            Err(Refusal::AlreadyAccepted {
+                answer: "31".to_owned()
+            })
+        );
+    }
+
+    #[test]
+    fn test_check_rate_limited() {
+        let mut history = History::default();
+        history.record(
+            1,
+            Part::One,
+            "5",
+            &Outcome::Wrong {
+                hint: None,
+                wait: Some(60),
+            },
+            1_000,
+        );
+        assert_eq!(
+            history.check(1, Part::One, "6", 1_030),
+            Err(Refusal::RateLimited { wait: 30 })
+        );
+        assert_eq!(history.check(1, Part::One, "6", 1_060), Ok(()));
+    }
+
+    #[test]
+    fn test_record_too_soon_is_not_a_rejection() {
+        let mut history = History::default();
+        history.record(1, Part::One, "5", &Outcome::TooSoon { wait: Some(10) }, 0);
+        assert!(history.submissions.is_empty());
+        assert_eq!(history.wait_until, 10);
+        assert_eq!(history.check(1, Part::One, "5", 10), Ok(()));
+    }
+
+    #[test]
+    fn test_history_save_and_load() {
+        let dir = env::temp_dir().join(format!("aoc_history_{}", std::process::id()));
+        let path = History::path(&dir, 2024);
+        assert_eq!(History::load(&path).unwrap(), History::default());
+
+        let history = history(&[(1, "100", Verdict::TooHigh)]);
+        history.save(&path).unwrap();
+        assert_eq!(History::load(&path).unwrap(), history);
+        assert!(fs::read_to_string(&path).unwrap().contains("\"too_high\""));
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_submit() {
+        let server = TestServer::start(vec![(200, TOO_HIGH), (200, CORRECT)]);
+        let submitter = Submitter::new(2024, HttpClient::new(&server.url, Some("s".to_owned())));
+        let mut history = History::default();
+
+        let outcome = submitter.submit(&mut history, 1, Part::Two, "40", 0);
+        assert_eq!(
+            outcome.unwrap(),
+            Outcome::Wrong {
+                hint: Some(Hint::TooHigh),
+                wait: Some(60)
+            }
+        );
+        // refused locally, the server is not asked
+        let outcome = submitter.submit(&mut history, 1, Part::Two, "40", 100);
+        assert!(matches!(
+            outcome,
+            Err(SubmitError::Refused(Refusal::AlreadyRejected { .. }))
+        ));
+        let outcome = submitter.submit(&mut history, 1, Part::Two, "31", 100);
+        assert_eq!(outcome.unwrap(), Outcome::Correct);
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 2);
+        assert_eq!(requests[0].path, "/2024/day/1/answer");
+        assert_eq!(requests[0].body, "level=2&answer=40");
+        assert_eq!(requests[1].body, "level=2&answer=31");
+        assert_eq!(history.submissions.len(), 2);
+    }
+}
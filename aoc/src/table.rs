@@ -0,0 +1,50 @@
+// left-aligned columns two spaces apart, sized to their widest cell, without trailing spaces
+pub fn render(rows: &[Vec<String>]) -> String {
+    let mut widths = vec![0; rows.iter().map(Vec::len).max().unwrap_or(0)];
+    for row in rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+    rows.iter()
+        .map(|row| {
+            let line = row
+                .iter()
+                .zip(&widths)
+                .map(|(cell, width)| format!("{:<width$}", cell, width = width))
+                .collect::<Vec<_>>()
+                .join("  ");
+            line.trim_end().to_owned() + "\n"
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn row(cells: &[&str]) -> Vec<String> {
+        cells.iter().map(|&c| c.to_owned()).collect()
+    }
+
+    #[test]
+    fn test_render() {
+        let rows = [
+            row(&["Day", "Answer"]),
+            row(&["01", "11"]),
+            row(&["02", ""]),
+        ];
+        assert_eq!(render(&rows), "Day  Answer\n01   11\n02\n");
+    }
+
+    #[test]
+    fn test_render_counts_chars() {
+        let rows = [row(&["µs", "x"]), row(&["ab", "y"])];
+        assert_eq!(render(&rows), "µs  x\nab  y\n");
+    }
+
+    #[test]
+    fn test_render_empty() {
+        assert_eq!(render(&[]), "");
+    }
+}
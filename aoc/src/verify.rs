@@ -0,0 +1,219 @@
+use std::{fmt::Display, io};
+
+use aoc_core::{answers::Answers, input::InputSource, Part};
+
+use crate::{solutions::Entry, table};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    Pass,
+    Fail,
+    Missing,
+    NoInput,
+    Error,
+}
+
+impl Status {
+    pub fn is_failure(&self) -> bool {
+        matches!(self, Self::Fail | Self::Error)
+    }
+}
+
+impl Display for Status {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Pass => write!(f, "pass"),
+            Self::Fail => write!(f, "FAIL"),
+            Self::Missing => write!(f, "missing"),
+            Self::NoInput => write!(f, "no input"),
+            Self::Error => write!(f, "ERROR"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Row {
+    pub day: u8,
+    pub part: Option<Part>,
+    pub status: Status,
+    pub answer: String,
+    pub expected: String,
+}
+
+impl Row {
+    fn without_part(day: u8, status: Status, message: String) -> Self {
+        Self {
+            day,
+            part: None,
+            status,
+            answer: message,
+            expected: String::new(),
+        }
+    }
+}
+
+pub fn verify(entry: &Entry, parts: &[Part], source: &InputSource, answers: &Answers) -> Vec<Row> {
+    check(entry, parts, &source.name(), source.read(), answers)
+}
+
+fn check(
+    entry: &Entry,
+    parts: &[Part],
+    input_name: &str,
+    input: io::Result<String>,
+    answers: &Answers,
+) -> Vec<Row> {
+    let input = match input {
+        Ok(input) => input,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => {
+            return vec![Row::without_part(
+                entry.day,
+                Status::NoInput,
+                input_name.to_owned(),
+            )];
+        }
+        Err(e) => {
+            let message = format!("could not read {}: {}", input_name, e);
+            return vec![Row::without_part(entry.day, Status::Error, message)];
+        }
+    };
+    let computed = match (entry.solve)(parts, &input) {
+        Ok(computed) => computed,
+        Err(e) => {
+            let message = format!("could not parse {}: {}", input_name, e);
+            return vec![Row::without_part(entry.day, Status::Error, message)];
+        }
+    };
+    let mut rows = vec![];
+    for (part, answer) in parts.iter().zip(computed) {
+        let expected = answers.get(entry.day, *part, input_name);
+        let status = match expected {
+            Some(expected) if expected == answer => Status::Pass,
+            Some(_) => Status::Fail,
+            None => Status::Missing,
+        };
+        rows.push(Row {
+            day: entry.day,
+            part: Some(*part),
+            status,
+            answer,
+            expected: expected.unwrap_or_default().to_owned(),
+        });
+    }
+    rows
+}
+
+pub fn table(rows: &[Row]) -> String {
+    let header = ["Day", "Part", "Status", "Answer", "Expected"].map(String::from);
+    let cells: Vec<Vec<String>> = std::iter::once(header.to_vec())
+        .chain(rows.iter().map(|row| {
+            vec![
+                format!("{:02}", row.day),
+                row.part.map_or("-".to_owned(), |p| p.to_string()),
+                row.status.to_string(),
+                row.answer.clone(),
+                row.expected.clone(),
+            ]
+        }))
+        .collect();
+    table::render(&cells)
+}
+
+pub fn summary(rows: &[Row]) -> String {
+    let count = |status| rows.iter().filter(|r| r.status == status).count();
+    format!(
+        "{} passed, {} failed, {} missing, {} without input, {} errors",
+        count(Status::Pass),
+        count(Status::Fail),
+        count(Status::Missing),
+        count(Status::NoInput),
+        count(Status::Error)
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::solutions;
+
+    const EXAMPLE: &str = "3   4\n4   3\n2   5\n1   3\n3   9\n3   3\n";
+
+    fn check_example(answers: &str, input: io::Result<String>) -> Vec<Row> {
+        let answers = Answers::parse(answers).unwrap();
+        let entry = solutions::get(1).unwrap();
+        check(entry, &Part::variants(), "example.txt", input, &answers)
+    }
+
+    #[test]
+    fn test_check_pass_and_missing() {
+        let rows = check_example("01 1 example.txt 11", Ok(EXAMPLE.to_owned()));
+        assert_eq!(
+            rows,
+            vec![
+                Row {
+                    day: 1,
+                    part: Some(Part::One),
+                    status: Status::Pass,
+                    answer: "11".to_owned(),
+                    expected: "11".to_owned()
+                },
+                Row {
+                    day: 1,
+                    part: Some(Part::Two),
+                    status: Status::Missing,
+                    answer: "31".to_owned(),
+                    expected: "".to_owned()
+                }
+            ]
+        );
+    }
+
+    #[test]
+    fn test_check_fail() {
+        let rows = check_example("01 2 example.txt 30", Ok(EXAMPLE.to_owned()));
+        assert_eq!(rows[1].status, Status::Fail);
+        assert_eq!(rows[1].expected, "30");
+    }
+
+    #[test]
+    fn test_check_other_input() {
+        let rows = check_example("01 1 other.txt 11", Ok(EXAMPLE.to_owned()));
+        assert_eq!(rows[0].status, Status::Missing);
+    }
+
+    #[test]
+    fn test_check_no_input() {
+        let rows = check_example("", Err(io::ErrorKind::NotFound.into()));
+        assert_eq!(rows.len(), 1);
+        assert_eq!(rows[0].status, Status::NoInput);
+        assert!(!rows[0].status.is_failure());
+    }
+
+    #[test]
+    fn test_check_parse_error() {
+        let rows = check_example("", Ok("3 x\n".to_owned()));
+        assert_eq!(rows.len(), 1);
+        assert_eq!(rows[0].status, Status::Error);
+        assert_eq!(
+            rows[0].answer,
+            "could not parse example.txt: line 1, column 3: expected a number, found \"x\""
+        );
+    }
+
+    #[test]
+    fn test_table() {
+        let rows = check_example("01 1 example.txt 11", Ok(EXAMPLE.to_owned()));
+        assert_eq!(
+            table(&rows),
+            "Day  Part  Status   Answer  Expected
+01   1     pass     11      11
+01   2     missing  31
+"
+        );
+        assert_eq!(
+            summary(&rows),
+            "1 passed, 0 failed, 1 missing, 0 without input, 0 errors"
+        );
+    }
+}
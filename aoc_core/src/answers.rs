@@ -0,0 +1,234 @@
+use std::{
+    collections::HashMap,
+    error::Error,
+    fmt::Display,
+    fs::read_to_string,
+    io,
+    path::{Path, PathBuf},
+};
+
+use crate::{
+    input::{workspace_dir, InputSource},
+    location::Location,
+    Part, Solution,
+};
+
+pub const ANSWERS_FILE: &str = "answers.txt";
+
+#[derive(Debug)]
+pub enum AnswersError {
+    Io { path: PathBuf, source: io::Error },
+    MissingColumns { location: Location },
+    InvalidDay { location: Location, token: String },
+    InvalidPart { location: Location, token: String },
+    Duplicate { location: Location },
+}
+
+impl Display for AnswersError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Io { path, source } => write!(f, "{}: {}", path.display(), source),
+            Self::MissingColumns { location } => {
+                write!(f, "{}: expected \"day part input answer\"", location)
+            }
+            Self::InvalidDay { location, token } => {
+                write!(f, "{}: expected a day, found \"{}\"", location, token)
+            }
+            Self::InvalidPart { location, token } => {
+                write!(f, "{}: expected part 1 or 2, found \"{}\"", location, token)
+            }
+            Self::Duplicate { location } => write!(
+                f,
+                "{}: answer for this day, part and input is already given",
+                location
+            ),
+        }
+    }
+}
+
+impl Error for AnswersError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Io { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+// known answers by day, part and input name (see `InputSource::name`)
+#[derive(Debug, Default, PartialEq, Eq, Clone)]
+pub struct Answers {
+    answers: HashMap<(u8, Part, String), String>,
+}
+
+impl Answers {
+    // one answer per line: "day part input answer", '#' starts a comment
+    pub fn parse(content: &str) -> Result<Self, AnswersError> {
+        let mut answers = HashMap::new();
+        for (i, line) in content.lines().enumerate() {
+            let text = line.split('#').next().unwrap_or_default().trim_end();
+            if text.trim().is_empty() {
+                continue;
+            }
+            let missing = || AnswersError::MissingColumns {
+                location: Location::end_of(i, line),
+            };
+            let (day, rest) = next_column(text).ok_or_else(missing)?;
+            let (part, rest) = next_column(rest).ok_or_else(missing)?;
+            let (input, answer) = next_column(rest).ok_or_else(missing)?;
+            let day = day.parse().map_err(|_| AnswersError::InvalidDay {
+                location: Location::of(i, line, day),
+                token: day.to_owned(),
+            })?;
+            let part = part
+                .parse()
+                .ok()
+                .and_then(Part::from_number)
+                .ok_or_else(|| AnswersError::InvalidPart {
+                    location: Location::of(i, line, part),
+                    token: part.to_owned(),
+                })?;
+            let key = (day, part, input.to_owned());
+            if answers.insert(key, answer.to_owned()).is_some() {
+                return Err(AnswersError::Duplicate {
+                    location: Location::new(i + 1, 1),
+                });
+            }
+        }
+        Ok(Self { answers })
+    }
+
+    pub fn load(path: &Path) -> Result<Self, AnswersError> {
+        let content = read_to_string(path).map_err(|source| AnswersError::Io {
+            path: path.to_path_buf(),
+            source,
+        })?;
+        Self::parse(&content)
+    }
+
+    pub fn get(&self, day: u8, part: Part, input: &str) -> Option<&str> {
+        self.answers
+            .get(&(day, part, input.to_owned()))
+            .map(String::as_str)
+    }
+}
+
+fn next_column(s: &str) -> Option<(&str, &str)> {
+    let (column, rest) = s.trim_start().split_once(char::is_whitespace)?;
+    let rest = rest.trim_start();
+    (!rest.is_empty()).then_some((column, rest))
+}
+
+pub fn default_path() -> PathBuf {
+    workspace_dir().join(ANSWERS_FILE)
+}
+
+// checks a solution against the answers file, skips when the private input is absent
+pub fn assert_answers<S: Solution>() {
+    let source = InputSource::resolve(S::DAY, None);
+    let input = match source.read() {
+        Ok(input) => input,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => {
+            eprintln!("skipping day {:02}: {} not found", S::DAY, source);
+            return;
+        }
+        Err(e) => panic!("could not read {}: {}", source, e),
+    };
+    let answers = Answers::load(&default_path()).unwrap();
+    let input_name = source.name();
+    let parsed = S::parse(&input).unwrap();
+    for part in Part::variants() {
+        match answers.get(S::DAY, part, &input_name) {
+            Some(expected) => assert_eq!(
+                S::answer(part, &parsed),
+                expected,
+                "day {:02} part {}",
+                S::DAY,
+                part
+            ),
+            None => eprintln!(
+                "no answer for day {:02} part {} of {}",
+                S::DAY,
+                part,
+                input_name
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let answers = Answers::parse(
+            "# day part input answer
+01 1 puzzle_01/input.txt 2192892
+
+17 2 inputs/17.txt 1,2,3 # a comment
+",
+        )
+        .unwrap();
+        assert_eq!(
+            answers.get(1, Part::One, "puzzle_01/input.txt"),
+            Some("2192892")
+        );
+        assert_eq!(answers.get(17, Part::Two, "inputs/17.txt"), Some("1,2,3"));
+        assert_eq!(answers.get(1, Part::Two, "puzzle_01/input.txt"), None);
+        assert_eq!(answers.get(1, Part::One, "stdin"), None);
+    }
+
+    #[test]
+    fn test_parse_answer_with_spaces() {
+        let answers = Answers::parse("08 2 stdin HELLO  WORLD").unwrap();
+        assert_eq!(answers.get(8, Part::Two, "stdin"), Some("HELLO  WORLD"));
+    }
+
+    #[test]
+    fn test_parse_missing_columns() {
+        let result = Answers::parse("01 1 puzzle_01/input.txt 2\n02 1 stdin\n");
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "line 2, column 11: expected \"day part input answer\""
+        );
+    }
+
+    #[test]
+    fn test_parse_invalid_part() {
+        let result = Answers::parse("01 3 stdin 2");
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "line 1, column 4: expected part 1 or 2, found \"3\""
+        );
+    }
+
+    #[test]
+    fn test_parse_invalid_day() {
+        let result = Answers::parse("day 1 stdin 2");
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "line 1, column 1: expected a day, found \"day\""
+        );
+    }
+
+    #[test]
+    fn test_parse_duplicate() {
+        let result = Answers::parse("01 1 stdin 2\n1 1 stdin 3");
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "line 2, column 1: answer for this day, part and input is already given"
+        );
+    }
+
+    #[test]
+    fn test_load_missing_file() {
+        let result = Answers::load(Path::new("does/not/exist.txt"));
+        assert!(matches!(result, Err(AnswersError::Io { .. })));
+    }
+
+    #[test]
+    fn test_default_answers_file() {
+        assert!(Answers::load(&default_path()).is_ok());
+    }
+}
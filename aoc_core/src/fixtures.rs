@@ -0,0 +1,249 @@
+use std::{
+    env,
+    fs::{self, read_dir},
+    io,
+    path::{Path, PathBuf},
+};
+
+use crate::{Part, Solution};
+
+pub const FIXTURES_DIR: &str = "fixtures";
+pub const INPUT_EXTENSION: &str = "txt";
+pub const EXPECTED_EXTENSION: &str = "expected";
+const GENERATED_FILE: &str = "fixtures.rs";
+
+// an example input "name.txt" next to "name.expected" with one "part answer" per line
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Fixture {
+    pub name: String,
+    pub input: PathBuf,
+    pub expected: PathBuf,
+}
+
+pub fn find(dir: &Path) -> io::Result<Vec<Fixture>> {
+    if !dir.is_dir() {
+        return Ok(vec![]);
+    }
+    let mut fixtures = vec![];
+    for entry in read_dir(dir)? {
+        let input = entry?.path();
+        if input.extension().and_then(|e| e.to_str()) != Some(INPUT_EXTENSION) {
+            continue;
+        }
+        let expected = input.with_extension(EXPECTED_EXTENSION);
+        if !expected.is_file() {
+            return Err(io::Error::new(
+                io::ErrorKind::NotFound,
+                format!("{} has no {}", input.display(), expected.display()),
+            ));
+        }
+        let stem = input
+            .file_stem()
+            .and_then(|s| s.to_str())
+            .unwrap_or_default();
+        fixtures.push(Fixture {
+            name: test_name(stem),
+            input,
+            expected,
+        });
+    }
+    fixtures.sort_by(|a, b| a.name.cmp(&b.name));
+    Ok(fixtures)
+}
+
+fn test_name(stem: &str) -> String {
+    let name: String = stem
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() {
+                c.to_ascii_lowercase()
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    if name.starts_with(|c: char| c.is_ascii_alphabetic()) {
+        name
+    } else {
+        format!("example_{}", name)
+    }
+}
+
+pub fn render(fixtures: &[Fixture]) -> String {
+    fixtures
+        .iter()
+        .map(|f| {
+            format!(
+                "#[test]\nfn {}() {{\n    check({:?}, include_str!({:?}), include_str!({:?}));\n}}\n",
+                f.name,
+                f.name,
+                f.input.to_string_lossy(),
+                f.expected.to_string_lossy()
+            )
+        })
+        .collect()
+}
+
+// called from the build script of a puzzle, the tests are included by `fixture_tests!`
+pub fn generate() {
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").expect("run from a build script");
+    let out_dir = env::var("OUT_DIR").expect("run from a build script");
+    let dir = Path::new(&manifest_dir).join(FIXTURES_DIR);
+    // a missing path counts as changed on every build, so crates without fixtures watch the script
+    if dir.is_dir() {
+        println!("cargo:rerun-if-changed={}", dir.display());
+    } else {
+        println!("cargo:rerun-if-changed=build.rs");
+    }
+    let fixtures = find(&dir).unwrap_or_else(|e| panic!("could not read the fixtures: {}", e));
+    fs::write(Path::new(&out_dir).join(GENERATED_FILE), render(&fixtures))
+        .expect("could not write the fixture tests");
+}
+
+pub fn parse_expected(content: &str) -> Result<Vec<(Part, String)>, String> {
+    content
+        .lines()
+        .enumerate()
+        .filter(|(_, l)| !l.trim().is_empty())
+        .map(|(i, l)| {
+            let (part, answer) = l
+                .trim()
+                .split_once(char::is_whitespace)
+                .ok_or_else(|| format!("line {}: expected \"part answer\"", i + 1))?;
+            let part = part
+                .parse()
+                .ok()
+                .and_then(Part::from_number)
+                .ok_or_else(|| {
+                    format!("line {}: expected part 1 or 2, found \"{}\"", i + 1, part)
+                })?;
+            Ok((part, answer.trim().to_owned()))
+        })
+        .collect()
+}
+
+pub fn assert_fixture<S: Solution>(name: &str, input: &str, expected: &str) {
+    let expected = parse_expected(expected)
+        .unwrap_or_else(|e| panic!("{}.{}: {}", name, EXPECTED_EXTENSION, e));
+    let parsed = S::parse(input).unwrap();
+    for (part, answer) in expected {
+        assert_eq!(S::answer(part, &parsed), answer, "{} part {}", name, part);
+    }
+}
+
+#[macro_export]
+macro_rules! fixture_tests {
+    ($solution:ty) => {
+        #[cfg(test)]
+        mod fixtures {
+            use super::*;
+
+            #[allow(dead_code)]
+            fn check(name: &str, input: &str, expected: &str) {
+                $crate::fixtures::assert_fixture::<$solution>(name, input, expected);
+            }
+
+            include!(concat!(env!("OUT_DIR"), "/fixtures.rs"));
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("aoc_fixtures_{}_{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn test_find() {
+        let dir = temp_dir("find");
+        fs::write(dir.join("small.txt"), "1").unwrap();
+        fs::write(dir.join("small.expected"), "1 1").unwrap();
+        fs::write(dir.join("Large-2.txt"), "2").unwrap();
+        fs::write(dir.join("Large-2.expected"), "2 2").unwrap();
+        fs::write(dir.join("notes.md"), "").unwrap();
+
+        let result = find(&dir).unwrap();
+        assert_eq!(
+            result,
+            vec![
+                Fixture {
+                    name: "large_2".to_owned(),
+                    input: dir.join("Large-2.txt"),
+                    expected: dir.join("Large-2.expected")
+                },
+                Fixture {
+                    name: "small".to_owned(),
+                    input: dir.join("small.txt"),
+                    expected: dir.join("small.expected")
+                }
+            ]
+        );
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_find_missing_expected() {
+        let dir = temp_dir("missing");
+        fs::write(dir.join("small.txt"), "1").unwrap();
+        assert!(find(&dir).is_err());
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_find_no_dir() {
+        assert_eq!(find(Path::new("does/not/exist")).unwrap(), vec![]);
+    }
+
+    #[test]
+    fn test_test_name() {
+        assert_eq!(test_name("tiny-1"), "tiny_1");
+        assert_eq!(test_name("1"), "example_1");
+    }
+
+    #[test]
+    fn test_render() {
+        let fixtures = [Fixture {
+            name: "small".to_owned(),
+            input: PathBuf::from("/f/small.txt"),
+            expected: PathBuf::from("/f/small.expected"),
+        }];
+        assert_eq!(
+            render(&fixtures),
+            "#[test]
+fn small() {
+    check(\"small\", include_str!(\"/f/small.txt\"), include_str!(\"/f/small.expected\"));
+}
+"
+        );
+    }
+
+    #[test]
+    fn test_parse_expected() {
+        let result = parse_expected("1 11\n\n2  1,2,3\n");
+        assert_eq!(
+            result,
+            Ok(vec![
+                (Part::One, "11".to_owned()),
+                (Part::Two, "1,2,3".to_owned())
+            ])
+        );
+    }
+
+    #[test]
+    fn test_parse_expected_invalid() {
+        assert_eq!(
+            parse_expected("1 11\n3 4"),
+            Err("line 2: expected part 1 or 2, found \"3\"".to_owned())
+        );
+        assert_eq!(
+            parse_expected("11"),
+            Err("line 1: expected \"part answer\"".to_owned())
+        );
+    }
+}
@@ -0,0 +1,288 @@
+use std::{
+    env,
+    fmt::Display,
+    fs::read_to_string,
+    io::{self, Read},
+    path::{Path, PathBuf},
+};
+
+use crate::YEAR;
+
+pub const INPUT_ENV: &str = "AOC_INPUT";
+pub const CACHE_ENV: &str = "AOC_CACHE_DIR";
+const STDIN_ARG: &str = "-";
+const STDIN_NAME: &str = "stdin";
+const DAY_PLACEHOLDER: &str = "{day}";
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum InputSource {
+    Stdin,
+    File(PathBuf),
+    // the personal input of a day, stored in the user cache
+    Cached { day: u8, path: PathBuf },
+}
+
+impl InputSource {
+    // "-" reads from stdin, "{day}" in a path is replaced by the zero padded day
+    pub fn from_arg(arg: &str, day: u8) -> Self {
+        if arg == STDIN_ARG {
+            Self::Stdin
+        } else {
+            Self::File(PathBuf::from(
+                arg.replace(DAY_PLACEHOLDER, &format!("{:02}", day)),
+            ))
+        }
+    }
+
+    // lookup order: explicit argument, $AOC_INPUT, puzzle_NN/input.txt in the workspace, the cache
+    pub fn resolve(day: u8, arg: Option<&str>) -> Self {
+        Self::resolve_from(
+            day,
+            arg,
+            env::var(INPUT_ENV).ok().as_deref(),
+            cache_path(YEAR, day).as_deref(),
+        )
+    }
+
+    fn resolve_from(
+        day: u8,
+        arg: Option<&str>,
+        env_value: Option<&str>,
+        cached: Option<&Path>,
+    ) -> Self {
+        if let Some(a) = arg.or(env_value) {
+            return Self::from_arg(a, day);
+        }
+        let local = default_path(day);
+        match cached {
+            Some(path) if !local.exists() && path.exists() => Self::Cached {
+                day,
+                path: path.to_path_buf(),
+            },
+            _ => Self::File(local),
+        }
+    }
+
+    pub fn read(&self) -> io::Result<String> {
+        match self {
+            Self::Stdin => {
+                let mut content = String::new();
+                io::stdin().read_to_string(&mut content)?;
+                Ok(content)
+            }
+            Self::File(path) | Self::Cached { path, .. } => read_to_string(path),
+        }
+    }
+
+    // how the input is referred to in the answers file: the path relative to the workspace if possible,
+    // a cached input is the same as puzzle_NN/input.txt
+    pub fn name(&self) -> String {
+        match self {
+            Self::Stdin => STDIN_NAME.to_owned(),
+            Self::Cached { day, .. } => Self::File(default_path(*day)).name(),
+            Self::File(path) => relative_to_workspace(path)
+                .unwrap_or_else(|| path.clone())
+                .to_string_lossy()
+                .replace('\\', "/"),
+        }
+    }
+}
+
+fn relative_to_workspace(path: &Path) -> Option<PathBuf> {
+    let workspace = workspace_dir();
+    if let Ok(relative) = path.strip_prefix(&workspace) {
+        return Some(relative.to_path_buf());
+    }
+    let canonical = path.canonicalize().ok()?;
+    canonical
+        .strip_prefix(workspace.canonicalize().ok()?)
+        .ok()
+        .map(Path::to_path_buf)
+}
+
+impl Display for InputSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Stdin => write!(f, "{}", STDIN_NAME),
+            Self::File(path) | Self::Cached { path, .. } => write!(f, "{}", path.display()),
+        }
+    }
+}
+
+pub fn workspace_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("aoc_core lives inside the workspace")
+        .to_path_buf()
+}
+
+pub fn default_path(day: u8) -> PathBuf {
+    workspace_dir()
+        .join(format!("puzzle_{:02}", day))
+        .join("input.txt")
+}
+
+// $AOC_CACHE_DIR, $XDG_CACHE_HOME/aoc or ~/.cache/aoc
+pub fn cache_dir() -> Option<PathBuf> {
+    cache_dir_from(
+        env::var_os(CACHE_ENV).map(PathBuf::from),
+        env::var_os("XDG_CACHE_HOME").map(PathBuf::from),
+        env::var_os("HOME").map(PathBuf::from),
+    )
+}
+
+fn cache_dir_from(
+    aoc_cache: Option<PathBuf>,
+    xdg_cache: Option<PathBuf>,
+    home: Option<PathBuf>,
+) -> Option<PathBuf> {
+    let non_empty = |p: &PathBuf| !p.as_os_str().is_empty();
+    aoc_cache
+        .filter(non_empty)
+        .or_else(|| xdg_cache.filter(non_empty).map(|p| p.join("aoc")))
+        .or_else(|| home.filter(non_empty).map(|p| p.join(".cache").join("aoc")))
+}
+
+pub fn cache_path_in(cache_dir: &Path, year: u16, day: u8) -> PathBuf {
+    cache_dir
+        .join(year.to_string())
+        .join(format!("{:02}.txt", day))
+}
+
+pub fn cache_path(year: u16, day: u8) -> Option<PathBuf> {
+    cache_dir().map(|dir| cache_path_in(&dir, year, day))
+}
+
+pub fn read_input(day: u8) -> io::Result<String> {
+    InputSource::resolve(day, None).read()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_arg_stdin() {
+        assert_eq!(InputSource::from_arg("-", 3), InputSource::Stdin);
+    }
+
+    #[test]
+    fn test_from_arg_path() {
+        assert_eq!(
+            InputSource::from_arg("inputs/other.txt", 3),
+            InputSource::File(PathBuf::from("inputs/other.txt"))
+        );
+    }
+
+    #[test]
+    fn test_from_arg_day_placeholder() {
+        assert_eq!(
+            InputSource::from_arg("inputs/{day}.txt", 3),
+            InputSource::File(PathBuf::from("inputs/03.txt"))
+        );
+    }
+
+    #[test]
+    fn test_resolve_argument_before_env() {
+        let result = InputSource::resolve_from(7, Some("a.txt"), Some("b.txt"), None);
+        assert_eq!(result, InputSource::File(PathBuf::from("a.txt")));
+    }
+
+    #[test]
+    fn test_resolve_env() {
+        let result = InputSource::resolve_from(7, None, Some("-"), None);
+        assert_eq!(result, InputSource::Stdin);
+    }
+
+    #[test]
+    fn test_resolve_default() {
+        let result = InputSource::resolve_from(7, None, None, None);
+        assert_eq!(result, InputSource::File(default_path(7)));
+        assert!(default_path(7).ends_with("puzzle_07/input.txt"));
+        assert!(default_path(7)
+            .parent()
+            .unwrap()
+            .join("Cargo.toml")
+            .exists());
+    }
+
+    #[test]
+    fn test_resolve_cache() {
+        let cached = workspace_dir().join("Cargo.toml");
+        let result = InputSource::resolve_from(7, None, None, Some(&cached));
+        assert_eq!(
+            result,
+            InputSource::Cached {
+                day: 7,
+                path: cached.clone()
+            }
+        );
+        assert_eq!(result.name(), "puzzle_07/input.txt");
+        assert_eq!(result.to_string(), cached.display().to_string());
+    }
+
+    #[test]
+    fn test_resolve_cache_missing() {
+        let cached = PathBuf::from("does/not/exist/07.txt");
+        let result = InputSource::resolve_from(7, None, None, Some(&cached));
+        assert_eq!(result, InputSource::File(default_path(7)));
+    }
+
+    #[test]
+    fn test_cache_dir() {
+        let dir = |a: Option<&str>, x: Option<&str>, h: Option<&str>| {
+            cache_dir_from(
+                a.map(PathBuf::from),
+                x.map(PathBuf::from),
+                h.map(PathBuf::from),
+            )
+        };
+        assert_eq!(
+            dir(Some("/c"), Some("/x"), Some("/h")),
+            Some(PathBuf::from("/c"))
+        );
+        assert_eq!(
+            dir(Some(""), Some("/x"), Some("/h")),
+            Some(PathBuf::from("/x/aoc"))
+        );
+        assert_eq!(
+            dir(None, None, Some("/h")),
+            Some(PathBuf::from("/h/.cache/aoc"))
+        );
+        assert_eq!(dir(None, None, None), None);
+    }
+
+    #[test]
+    fn test_cache_path_in() {
+        assert_eq!(
+            cache_path_in(Path::new("/c"), 2024, 7),
+            PathBuf::from("/c/2024/07.txt")
+        );
+    }
+
+    #[test]
+    fn test_name() {
+        assert_eq!(InputSource::Stdin.name(), "stdin");
+        assert_eq!(
+            InputSource::File(default_path(7)).name(),
+            "puzzle_07/input.txt"
+        );
+        assert_eq!(
+            InputSource::File(workspace_dir().join("Cargo.toml")).name(),
+            "Cargo.toml"
+        );
+        assert_eq!(
+            InputSource::File(PathBuf::from("/elsewhere/07.txt")).name(),
+            "/elsewhere/07.txt"
+        );
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(InputSource::Stdin.to_string(), "stdin");
+        assert_eq!(
+            InputSource::File(PathBuf::from("a/b.txt")).to_string(),
+            "a/b.txt"
+        );
+    }
+}
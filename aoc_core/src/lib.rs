@@ -0,0 +1,133 @@
+use std::{error::Error, fmt::Display};
+
+pub mod answers;
+pub mod fixtures;
+pub mod input;
+pub mod location;
+
+pub const YEAR: u16 = 2024;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub fn variants() -> Vec<Self> {
+        vec![Self::One, Self::Two]
+    }
+
+    pub fn from_number(n: u8) -> Option<Self> {
+        match n {
+            1 => Some(Self::One),
+            2 => Some(Self::Two),
+            _ => None,
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::One => write!(f, "1"),
+            Self::Two => write!(f, "2"),
+        }
+    }
+}
+
+pub trait Solution {
+    const DAY: u8;
+    const TITLE: &'static str;
+
+    type Input;
+    type Error: Error + 'static;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error>;
+    fn part_1(input: &Self::Input) -> impl Display;
+    fn part_2(input: &Self::Input) -> impl Display;
+
+    fn answer(part: Part, input: &Self::Input) -> String {
+        match part {
+            Part::One => Self::part_1(input).to_string(),
+            Part::Two => Self::part_2(input).to_string(),
+        }
+    }
+
+    // a picture of the input for `aoc run --debug`, None for days without one
+    fn debug(_input: &Self::Input, _color: bool) -> Option<String> {
+        None
+    }
+
+    // parses once and answers every part in order
+    fn solve(parts: &[Part], input: &str) -> Result<Vec<String>, Box<dyn Error>> {
+        let input = Self::parse(input)?;
+        Ok(parts
+            .iter()
+            .map(|&part| Self::answer(part, &input))
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::num::ParseIntError;
+
+    struct Sum;
+
+    impl Solution for Sum {
+        const DAY: u8 = 0;
+        const TITLE: &'static str = "Sum";
+
+        type Input = Vec<usize>;
+        type Error = ParseIntError;
+
+        fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+            input.split(',').map(|s| s.parse()).collect()
+        }
+
+        fn part_1(input: &Self::Input) -> impl Display {
+            input.iter().sum::<usize>()
+        }
+
+        fn part_2(input: &Self::Input) -> impl Display {
+            format!("{:?}", input)
+        }
+    }
+
+    #[test]
+    fn test_part_from_number() {
+        assert_eq!(Part::from_number(1), Some(Part::One));
+        assert_eq!(Part::from_number(2), Some(Part::Two));
+        assert_eq!(Part::from_number(3), None);
+    }
+
+    #[test]
+    fn test_solve() {
+        assert_eq!(Sum::solve(&[Part::One], "1,2").unwrap(), vec!["3"]);
+        assert_eq!(
+            Sum::solve(&Part::variants(), "1,2").unwrap(),
+            vec!["3", "[1, 2]"]
+        );
+        assert_eq!(
+            Sum::solve(&[Part::Two, Part::One], "1").unwrap(),
+            vec!["[1]", "1"]
+        );
+    }
+
+    #[test]
+    fn test_no_debug_output() {
+        assert_eq!(Sum::debug(&vec![1, 2], false), None);
+    }
+
+    #[test]
+    fn test_solve_error() {
+        let result = Sum::solve(&Part::variants(), "1,a");
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "invalid digit found in string"
+        );
+    }
+}
@@ -0,0 +1,81 @@
+use std::fmt::Display;
+
+// line and column are counted from 1, columns in chars
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub struct Location {
+    pub line: usize,
+    pub column: usize,
+}
+
+impl Location {
+    pub fn new(line: usize, column: usize) -> Self {
+        Self { line, column }
+    }
+
+    // `token` has to be a subslice of `line`, `line_index` is counted from 0
+    pub fn of(line_index: usize, line: &str, token: &str) -> Self {
+        let offset = (token.as_ptr() as usize).saturating_sub(line.as_ptr() as usize);
+        let column = line
+            .get(..offset)
+            .map_or(line.chars().count(), |before| before.chars().count());
+        Self::new(line_index + 1, column + 1)
+    }
+
+    pub fn after(line_index: usize, line: &str, token: &str) -> Self {
+        let start = Self::of(line_index, line, token);
+        Self::new(start.line, start.column + token.chars().count())
+    }
+
+    pub fn end_of(line_index: usize, line: &str) -> Self {
+        Self::after(line_index, line, line)
+    }
+}
+
+impl Display for Location {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "line {}, column {}", self.line, self.column)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_of() {
+        let line = "190: 10 19";
+        let token = line.split_whitespace().nth(1).unwrap();
+        assert_eq!(Location::of(2, line, token), Location::new(3, 6));
+    }
+
+    #[test]
+    fn test_of_start() {
+        let line = "abc";
+        assert_eq!(Location::of(0, line, line), Location::new(1, 1));
+    }
+
+    #[test]
+    fn test_of_unicode() {
+        let line = "äb c";
+        let token = line.split_whitespace().nth(1).unwrap();
+        assert_eq!(Location::of(0, line, token), Location::new(1, 4));
+    }
+
+    #[test]
+    fn test_after() {
+        let line = "p=0,4 v=3,-3";
+        let token = &line[..5];
+        assert_eq!(Location::after(0, line, token), Location::new(1, 6));
+    }
+
+    #[test]
+    fn test_end_of() {
+        assert_eq!(Location::end_of(4, "190"), Location::new(5, 4));
+        assert_eq!(Location::end_of(0, ""), Location::new(1, 1));
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(Location::new(3, 7).to_string(), "line 3, column 7");
+    }
+}
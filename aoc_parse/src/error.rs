@@ -0,0 +1,71 @@
+use std::{error::Error, fmt::Display};
+
+use aoc_core::location::Location;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum ParseError {
+    InvalidNumber {
+        location: Location,
+        token: String,
+    },
+    Expected {
+        location: Location,
+        expected: String,
+    },
+    UnexpectedToken {
+        location: Location,
+        token: String,
+    },
+    EmptyInput,
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidNumber { location, token } => {
+                write!(f, "{}: expected a number, found \"{}\"", location, token)
+            }
+            Self::Expected { location, expected } => {
+                write!(f, "{}: expected {}", location, expected)
+            }
+            Self::UnexpectedToken { location, token } => {
+                write!(f, "{}: expected end of line, found \"{}\"", location, token)
+            }
+            Self::EmptyInput => write!(f, "expected input, found an empty input"),
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        let error = ParseError::InvalidNumber {
+            location: Location::new(2, 5),
+            token: "x3".to_owned(),
+        };
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 5: expected a number, found \"x3\""
+        );
+
+        let error = ParseError::Expected {
+            location: Location::new(1, 4),
+            expected: "':'".to_owned(),
+        };
+        assert_eq!(error.to_string(), "line 1, column 4: expected ':'");
+
+        let error = ParseError::UnexpectedToken {
+            location: Location::new(3, 9),
+            token: "7".to_owned(),
+        };
+        assert_eq!(
+            error.to_string(),
+            "line 3, column 9: expected end of line, found \"7\""
+        );
+    }
+}
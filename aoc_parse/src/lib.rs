@@ -0,0 +1,135 @@
+use std::str::FromStr;
+
+pub use error::ParseError;
+pub use line::Line;
+
+pub mod error;
+pub mod line;
+
+pub fn lines(input: &str) -> impl Iterator<Item = Line<'_>> {
+    input.lines().enumerate().map(|(i, l)| Line::new(i, l))
+}
+
+pub fn non_blank_lines(input: &str) -> impl Iterator<Item = Line<'_>> {
+    lines(input).filter(|l| !l.is_blank())
+}
+
+pub fn first_line(input: &str) -> Result<Line<'_>, ParseError> {
+    non_blank_lines(input).next().ok_or(ParseError::EmptyInput)
+}
+
+// blocks of non blank lines separated by one or more blank lines
+pub fn paragraphs(input: &str) -> Vec<Vec<Line<'_>>> {
+    let mut paragraphs = vec![];
+    let mut current = vec![];
+    for line in lines(input) {
+        if line.is_blank() {
+            if !current.is_empty() {
+                paragraphs.push(current);
+                current = vec![];
+            }
+        } else {
+            current.push(line);
+        }
+    }
+    if !current.is_empty() {
+        paragraphs.push(current);
+    }
+    paragraphs
+}
+
+pub fn lines_array<'a, const N: usize>(lines: &[Line<'a>]) -> Result<[Line<'a>; N], ParseError> {
+    if let Some(line) = lines.get(N) {
+        return Err(ParseError::UnexpectedToken {
+            location: line.location_of(line.text),
+            token: line.text.to_owned(),
+        });
+    }
+    lines.try_into().map_err(|_| ParseError::Expected {
+        location: aoc_core::location::Location::new(lines.last().map_or(1, |l| l.index + 2), 1),
+        expected: format!("{} lines", N),
+    })
+}
+
+pub fn numbers_per_line<T: FromStr>(input: &str) -> Result<Vec<Vec<T>>, ParseError> {
+    non_blank_lines(input).map(|l| l.numbers()).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::location::Location;
+
+    use super::*;
+
+    #[test]
+    fn test_lines() {
+        let result: Vec<_> = lines("a\n\nb").collect();
+        assert_eq!(
+            result,
+            vec![Line::new(0, "a"), Line::new(1, ""), Line::new(2, "b")]
+        );
+    }
+
+    #[test]
+    fn test_non_blank_lines() {
+        let result: Vec<_> = non_blank_lines("a\n  \nb\n").collect();
+        assert_eq!(result, vec![Line::new(0, "a"), Line::new(2, "b")]);
+    }
+
+    #[test]
+    fn test_first_line() {
+        assert_eq!(first_line("\n125 17\n"), Ok(Line::new(1, "125 17")));
+        assert_eq!(first_line(""), Err(ParseError::EmptyInput));
+    }
+
+    #[test]
+    fn test_paragraphs() {
+        let result = paragraphs("a\nb\n\n\nc\n\n");
+        assert_eq!(
+            result,
+            vec![
+                vec![Line::new(0, "a"), Line::new(1, "b")],
+                vec![Line::new(4, "c")]
+            ]
+        );
+    }
+
+    #[test]
+    fn test_paragraphs_empty() {
+        assert!(paragraphs("\n\n").is_empty());
+    }
+
+    #[test]
+    fn test_lines_array() {
+        let paragraph = &paragraphs("a\nb\n\nc")[0];
+        let [a, b] = lines_array(paragraph).unwrap();
+        assert_eq!((a.text, b.text), ("a", "b"));
+    }
+
+    #[test]
+    fn test_lines_array_too_few() {
+        let paragraph = &paragraphs("a\nb\n\nc")[0];
+        assert_eq!(
+            lines_array::<3>(paragraph),
+            Err(ParseError::Expected {
+                location: Location::new(3, 1),
+                expected: "3 lines".to_owned()
+            })
+        );
+    }
+
+    #[test]
+    fn test_lines_array_too_many() {
+        let paragraph = &paragraphs("a\nb\nc")[0];
+        assert_eq!(
+            lines_array::<2>(paragraph).unwrap_err().to_string(),
+            "line 3, column 1: expected end of line, found \"c\""
+        );
+    }
+
+    #[test]
+    fn test_numbers_per_line() {
+        let result = numbers_per_line::<i32>("7 6 4\n\n1 -2\n");
+        assert_eq!(result, Ok(vec![vec![7, 6, 4], vec![1, -2]]));
+    }
+}
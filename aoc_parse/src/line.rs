@@ -0,0 +1,383 @@
+use std::str::FromStr;
+
+use aoc_core::location::Location;
+
+use crate::error::ParseError;
+
+// all `token` arguments have to be subslices of `text`, so errors can point at them
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Line<'a> {
+    pub index: usize,
+    pub text: &'a str,
+}
+
+impl<'a> Line<'a> {
+    pub fn new(index: usize, text: &'a str) -> Self {
+        Self { index, text }
+    }
+
+    pub fn is_blank(&self) -> bool {
+        self.text.trim().is_empty()
+    }
+
+    pub fn location_of(&self, token: &str) -> Location {
+        Location::of(self.index, self.text, token)
+    }
+
+    pub fn location_after(&self, token: &str) -> Location {
+        Location::after(self.index, self.text, token)
+    }
+
+    pub fn end(&self) -> Location {
+        Location::end_of(self.index, self.text)
+    }
+
+    pub fn number<T: FromStr>(&self, token: &'a str) -> Result<T, ParseError> {
+        token.parse().map_err(|_| ParseError::InvalidNumber {
+            location: self.location_of(token),
+            token: token.to_owned(),
+        })
+    }
+
+    // whitespace separated numbers, e.g. "7 6 4 2 1"
+    pub fn numbers<T: FromStr>(&self) -> Result<Vec<T>, ParseError> {
+        self.text
+            .split_whitespace()
+            .map(|token| self.number(token))
+            .collect()
+    }
+
+    pub fn numbers_array<T: FromStr, const N: usize>(&self) -> Result<[T; N], ParseError> {
+        let tokens: Vec<&str> = self.text.split_whitespace().collect();
+        self.exactly(&tokens, N)?;
+        Ok(into_array(
+            tokens
+                .iter()
+                .map(|token| self.number(token))
+                .collect::<Result<Vec<T>, _>>()?,
+        ))
+    }
+
+    // numbers inside `token` separated by `separator`, e.g. "75,47,61"
+    pub fn separated<T: FromStr>(
+        &self,
+        token: &'a str,
+        separator: char,
+    ) -> Result<Vec<T>, ParseError> {
+        token
+            .split(separator)
+            .map(|value| self.number(value.trim()))
+            .collect()
+    }
+
+    pub fn pair<T: FromStr>(&self, token: &'a str, separator: char) -> Result<(T, T), ParseError> {
+        let (a, b) = token
+            .split_once(separator)
+            .ok_or_else(|| ParseError::Expected {
+                location: self.location_after(token),
+                expected: format!("'{}'", separator),
+            })?;
+        Ok((self.number(a.trim())?, self.number(b.trim())?))
+    }
+
+    pub fn split_once(&self, separator: char) -> Result<(&'a str, &'a str), ParseError> {
+        self.text
+            .split_once(separator)
+            .ok_or_else(|| ParseError::Expected {
+                location: self.end(),
+                expected: format!("'{}'", separator),
+            })
+    }
+
+    // "target: a b c"
+    pub fn target<T: FromStr>(&self) -> Result<(T, Vec<T>), ParseError> {
+        let (target, rest) = self.split_once(':')?;
+        let target = self.number(target.trim())?;
+        let values: Vec<T> = rest
+            .split_whitespace()
+            .map(|token| self.number(token))
+            .collect::<Result<_, _>>()?;
+        if values.is_empty() {
+            return Err(ParseError::Expected {
+                location: self.end(),
+                expected: "at least one number".to_owned(),
+            });
+        }
+        Ok((target, values))
+    }
+
+    // whitespace separated "key=value" pairs, e.g. "p=0,4 v=3,-3"
+    pub fn key_values(&self) -> Result<Vec<(&'a str, &'a str)>, ParseError> {
+        self.text
+            .split_whitespace()
+            .map(|token| {
+                token.split_once('=').ok_or_else(|| ParseError::Expected {
+                    location: self.location_after(token),
+                    expected: "'='".to_owned(),
+                })
+            })
+            .collect()
+    }
+
+    pub fn value(&self, key: &str) -> Result<&'a str, ParseError> {
+        self.key_values()?
+            .into_iter()
+            .find(|(k, _)| *k == key)
+            .map(|(_, v)| v)
+            .ok_or_else(|| ParseError::Expected {
+                location: self.end(),
+                expected: format!("\"{}=\"", key),
+            })
+    }
+
+    // every integer in the text, a '-' directly in front of the digits makes it negative
+    pub fn signed_ints<T: FromStr>(&self) -> Result<Vec<T>, ParseError> {
+        self.signed_int_tokens()
+            .into_iter()
+            .map(|token| self.number(token))
+            .collect()
+    }
+
+    pub fn signed_ints_array<T: FromStr, const N: usize>(&self) -> Result<[T; N], ParseError> {
+        let tokens = self.signed_int_tokens();
+        self.exactly(&tokens, N)?;
+        Ok(into_array(
+            tokens
+                .iter()
+                .map(|token| self.number(token))
+                .collect::<Result<Vec<T>, _>>()?,
+        ))
+    }
+
+    fn signed_int_tokens(&self) -> Vec<&'a str> {
+        let bytes = self.text.as_bytes();
+        let mut tokens = vec![];
+        let mut i = 0;
+        while i < bytes.len() {
+            let start = i;
+            if bytes[i] == b'-' && bytes.get(i + 1).is_some_and(u8::is_ascii_digit) {
+                i += 1;
+            }
+            if bytes[i].is_ascii_digit() {
+                while i < bytes.len() && bytes[i].is_ascii_digit() {
+                    i += 1;
+                }
+                tokens.push(&self.text[start..i]);
+            } else {
+                i = start + 1;
+            }
+        }
+        tokens
+    }
+
+    fn exactly(&self, tokens: &[&str], n: usize) -> Result<(), ParseError> {
+        if let Some(token) = tokens.get(n) {
+            return Err(ParseError::UnexpectedToken {
+                location: self.location_of(token),
+                token: token.to_string(),
+            });
+        }
+        if tokens.len() < n {
+            return Err(ParseError::Expected {
+                location: self.end(),
+                expected: format!("{} numbers", n),
+            });
+        }
+        Ok(())
+    }
+}
+
+fn into_array<T, const N: usize>(values: Vec<T>) -> [T; N] {
+    values
+        .try_into()
+        .unwrap_or_else(|_| panic!("length is checked before"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_numbers() {
+        let line = Line::new(0, "7 6  4 2 1");
+        assert_eq!(line.numbers::<u32>(), Ok(vec![7, 6, 4, 2, 1]));
+    }
+
+    #[test]
+    fn test_numbers_signed() {
+        let line = Line::new(0, "-7 6");
+        assert_eq!(line.numbers::<i32>(), Ok(vec![-7, 6]));
+    }
+
+    #[test]
+    fn test_numbers_invalid() {
+        let line = Line::new(3, "7 6 x 2");
+        assert_eq!(
+            line.numbers::<u32>(),
+            Err(ParseError::InvalidNumber {
+                location: Location::new(4, 5),
+                token: "x".to_owned()
+            })
+        );
+    }
+
+    #[test]
+    fn test_numbers_array() {
+        let line = Line::new(0, "3   4");
+        assert_eq!(line.numbers_array::<u32, 2>(), Ok([3, 4]));
+    }
+
+    #[test]
+    fn test_numbers_array_too_few() {
+        let line = Line::new(0, "3");
+        assert_eq!(
+            line.numbers_array::<u32, 2>(),
+            Err(ParseError::Expected {
+                location: Location::new(1, 2),
+                expected: "2 numbers".to_owned()
+            })
+        );
+    }
+
+    #[test]
+    fn test_numbers_array_too_many() {
+        let line = Line::new(0, "3 4 5");
+        assert_eq!(
+            line.numbers_array::<u32, 2>(),
+            Err(ParseError::UnexpectedToken {
+                location: Location::new(1, 5),
+                token: "5".to_owned()
+            })
+        );
+    }
+
+    #[test]
+    fn test_separated() {
+        let line = Line::new(0, "75,47,61");
+        assert_eq!(line.separated::<u32>(line.text, ','), Ok(vec![75, 47, 61]));
+    }
+
+    #[test]
+    fn test_separated_empty_value() {
+        let line = Line::new(0, "75,,61");
+        assert_eq!(
+            line.separated::<u32>(line.text, ',')
+                .unwrap_err()
+                .to_string(),
+            "line 1, column 4: expected a number, found \"\""
+        );
+    }
+
+    #[test]
+    fn test_pair() {
+        let line = Line::new(0, "47|53");
+        assert_eq!(line.pair::<u32>(line.text, '|'), Ok((47, 53)));
+    }
+
+    #[test]
+    fn test_pair_missing_separator() {
+        let line = Line::new(0, "p=3 v=4");
+        let token = &line.text[2..3];
+        assert_eq!(
+            line.pair::<u32>(token, ','),
+            Err(ParseError::Expected {
+                location: Location::new(1, 4),
+                expected: "','".to_owned()
+            })
+        );
+    }
+
+    #[test]
+    fn test_target() {
+        let line = Line::new(0, "3267: 81 40 27");
+        assert_eq!(line.target::<u64>(), Ok((3267, vec![81, 40, 27])));
+    }
+
+    #[test]
+    fn test_target_missing_colon() {
+        let line = Line::new(4, "3267 81 40 27");
+        assert_eq!(
+            line.target::<u64>().unwrap_err().to_string(),
+            "line 5, column 14: expected ':'"
+        );
+    }
+
+    #[test]
+    fn test_target_missing_values() {
+        let line = Line::new(0, "3267:");
+        assert_eq!(
+            line.target::<u64>().unwrap_err().to_string(),
+            "line 1, column 6: expected at least one number"
+        );
+    }
+
+    #[test]
+    fn test_key_values() {
+        let line = Line::new(0, "p=0,4 v=3,-3");
+        assert_eq!(line.key_values(), Ok(vec![("p", "0,4"), ("v", "3,-3")]));
+    }
+
+    #[test]
+    fn test_key_values_missing_equals() {
+        let line = Line::new(0, "p=0,4 v");
+        assert_eq!(
+            line.key_values().unwrap_err().to_string(),
+            "line 1, column 8: expected '='"
+        );
+    }
+
+    #[test]
+    fn test_value() {
+        let line = Line::new(0, "p=0,4 v=3,-3");
+        assert_eq!(line.value("v"), Ok("3,-3"));
+        assert_eq!(line.pair::<i32>(line.value("v").unwrap(), ','), Ok((3, -3)));
+    }
+
+    #[test]
+    fn test_value_missing() {
+        let line = Line::new(2, "p=0,4");
+        assert_eq!(
+            line.value("v").unwrap_err().to_string(),
+            "line 3, column 6: expected \"v=\""
+        );
+    }
+
+    #[test]
+    fn test_signed_ints() {
+        let line = Line::new(0, "Button A: X+94, Y-34");
+        assert_eq!(line.signed_ints::<i64>(), Ok(vec![94, -34]));
+    }
+
+    #[test]
+    fn test_signed_ints_noise() {
+        let line = Line::new(0, "a-b 12-3 --4 x");
+        assert_eq!(line.signed_ints::<i64>(), Ok(vec![12, -3, -4]));
+    }
+
+    #[test]
+    fn test_signed_ints_unsigned_target() {
+        let line = Line::new(0, "x=-1");
+        assert_eq!(
+            line.signed_ints::<u32>(),
+            Err(ParseError::InvalidNumber {
+                location: Location::new(1, 3),
+                token: "-1".to_owned()
+            })
+        );
+    }
+
+    #[test]
+    fn test_signed_ints_array() {
+        let line = Line::new(0, "Prize: X=8400, Y=5400");
+        assert_eq!(line.signed_ints_array::<i64, 2>(), Ok([8400, 5400]));
+    }
+
+    #[test]
+    fn test_signed_ints_array_too_few() {
+        let line = Line::new(1, "Button B: X+22");
+        assert_eq!(
+            line.signed_ints_array::<i64, 2>().unwrap_err().to_string(),
+            "line 2, column 15: expected 2 numbers"
+        );
+    }
+}
@@ -0,0 +1,106 @@
+use std::fmt::Display;
+
+use aoc_core::Solution;
+use aoc_parse::non_blank_lines;
+pub use aoc_parse::ParseError;
+use distance::ordered_distance;
+use similarity::occurence_similarity;
+
+pub mod distance;
+pub mod similarity;
+
+pub type Id = usize;
+pub type Input = (Vec<Id>, Vec<Id>);
+
+pub struct Puzzle01;
+
+pub fn parse(input: &str) -> Result<Input, ParseError> {
+    non_blank_lines(input)
+        .map(|l| l.numbers_array::<Id, 2>().map(|[a, b]| (a, b)))
+        .collect()
+}
+
+pub fn part1((vec_a, vec_b): &Input) -> usize {
+    ordered_distance(&mut vec_a.clone(), &mut vec_b.clone())
+}
+
+pub fn part2((vec_a, vec_b): &Input) -> usize {
+    occurence_similarity(&mut vec_a.clone(), &mut vec_b.clone())
+}
+
+impl Solution for Puzzle01 {
+    const DAY: u8 = 1;
+    const TITLE: &'static str = "Historian Hysteria";
+
+    type Input = Input;
+    type Error = ParseError;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        parse(input)
+    }
+
+    fn part_1(input: &Self::Input) -> impl Display {
+        part1(input)
+    }
+
+    fn part_2(input: &Self::Input) -> impl Display {
+        part2(input)
+    }
+}
+
+aoc_core::fixture_tests!(Puzzle01);
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::{answers::assert_answers, location::Location};
+
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let result = parse("3   4\n4   3\n\n");
+        assert_eq!(result, Ok((vec![3, 4], vec![4, 3])));
+    }
+
+    #[test]
+    fn test_parse_invalid_number() {
+        let result = parse("3   4\n4   x3\n");
+        assert_eq!(
+            result,
+            Err(ParseError::InvalidNumber {
+                location: Location::new(2, 5),
+                token: "x3".to_owned()
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_missing_number() {
+        let result = parse("3   4\n4\n");
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "line 2, column 2: expected 2 numbers"
+        );
+    }
+
+    #[test]
+    fn test_parse_unexpected_token() {
+        let result = parse("3   4   5\n");
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "line 1, column 9: expected end of line, found \"5\""
+        );
+    }
+
+    #[test]
+    fn test_parts_share_input() {
+        let input = parse("3   4\n4   3\n2   5\n1   3\n3   9\n3   3\n").unwrap();
+        assert_eq!(part1(&input), 11);
+        assert_eq!(part2(&input), 31);
+    }
+
+    #[test]
+    fn test_main() {
+        assert_answers::<Puzzle01>();
+    }
+}
@@ -0,0 +1,73 @@
+use std::fmt::Display;
+
+use aoc_core::Solution;
+use aoc_parse::numbers_per_line;
+pub use aoc_parse::ParseError;
+use level_checker::{check_level, check_level_skip, LevelItem};
+
+pub mod level_checker;
+
+pub type Input = Vec<Vec<LevelItem>>;
+
+pub struct Puzzle02;
+
+pub fn parse(input: &str) -> Result<Input, ParseError> {
+    numbers_per_line(input)
+}
+
+pub fn part1(reports: &Input) -> usize {
+    reports
+        .iter()
+        .filter(|line_vec| check_level(line_vec))
+        .count()
+}
+
+pub fn part2(reports: &Input) -> usize {
+    reports
+        .iter()
+        .filter(|line_vec| check_level_skip(line_vec))
+        .count()
+}
+
+impl Solution for Puzzle02 {
+    const DAY: u8 = 2;
+    const TITLE: &'static str = "Red-Nosed Reports";
+
+    type Input = Input;
+    type Error = ParseError;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        parse(input)
+    }
+
+    fn part_1(input: &Self::Input) -> impl Display {
+        part1(input)
+    }
+
+    fn part_2(input: &Self::Input) -> impl Display {
+        part2(input)
+    }
+}
+
+aoc_core::fixture_tests!(Puzzle02);
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::answers::assert_answers;
+
+    use super::*;
+
+    #[test]
+    fn test_parse_invalid_number() {
+        let result = parse("7 6 4\n1 2 7 - 9");
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "line 2, column 7: expected a number, found \"-\""
+        );
+    }
+
+    #[test]
+    fn test_main() {
+        assert_answers::<Puzzle02>();
+    }
+}
@@ -0,0 +1,56 @@
+use std::{convert::Infallible, fmt::Display};
+
+use aoc_core::Solution;
+use parse_mul::{parse_mul, parse_mul_disable};
+
+pub mod parse_mul;
+
+pub type Input = String;
+
+pub struct Puzzle03;
+
+pub fn parse(input: &str) -> Result<Input, Infallible> {
+    Ok(input.to_owned())
+}
+
+pub fn part1(input: &Input) -> usize {
+    input.lines().map(parse_mul).sum()
+}
+
+pub fn part2(input: &Input) -> usize {
+    parse_mul_disable(input)
+}
+
+impl Solution for Puzzle03 {
+    const DAY: u8 = 3;
+    const TITLE: &'static str = "Mull It Over";
+
+    type Input = Input;
+    type Error = Infallible;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        parse(input)
+    }
+
+    fn part_1(input: &Self::Input) -> impl Display {
+        part1(input)
+    }
+
+    fn part_2(input: &Self::Input) -> impl Display {
+        part2(input)
+    }
+}
+
+aoc_core::fixture_tests!(Puzzle03);
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::answers::assert_answers;
+
+    use super::*;
+
+    #[test]
+    fn test_main() {
+        assert_answers::<Puzzle03>();
+    }
+}
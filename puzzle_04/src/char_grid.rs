@@ -1,91 +1,21 @@
-use std::ops::{Index, IndexMut};
+pub use crate::grid::{Grid, GridError};
 
-use crate::{direction::Direction, vector::BoundVector2D};
-
-#[derive(Debug, Default, Clone)]
-pub struct CharGrid {
-    pub rows: usize,
-    pub cols: usize,
-    grid: Vec<char>,
-}
+pub type CharGrid = Grid<char>;
 
 impl CharGrid {
-    pub fn from_string(input: &str) -> Result<CharGrid, &'static str> {
-        let rows_vec: Vec<_> = input.lines().collect();
-        if rows_vec.is_empty() {
-            return Ok(CharGrid::default());
-        }
-
-        let cols = rows_vec[0].len();
-        if rows_vec.iter().any(|row| row.len() != cols) {
-            return Err("All rows must have the same size.");
-        }
-
-        let grid = rows_vec.iter().flat_map(|row| row.chars()).collect();
-
-        let rows = rows_vec.iter().len();
-
-        Ok(CharGrid { rows, cols, grid })
-    }
-
-    fn get_internal_index(&self, index: BoundVector2D) -> usize {
-        self.cols * index.x + index.y
-    }
-
-    pub fn get(&self, index: BoundVector2D) -> Option<&char> {
-        let idx = index.bound(self.rows, self.cols)?;
-        self.grid.get(self.get_internal_index(idx))
-    }
-
-    pub fn set(&mut self, index: BoundVector2D, c: char) -> Option<()> {
-        let idx = index.bound(self.rows, self.cols)?;
-        let index = self.get_internal_index(idx);
-        self.grid[index] = c;
-        Some(())
-    }
-
-    pub fn iter_indices(&self) -> impl Iterator<Item = BoundVector2D> + '_ {
-        (0..self.rows).flat_map(move |x| {
-            (0..self.cols).map(move |y| BoundVector2D::new(x, y, self.rows, self.cols).unwrap())
-        })
-    }
-
-    pub fn get_vector_from_direction(
-        &self,
-        start: &BoundVector2D,
-        direction: &Direction,
-        length: &usize,
-    ) -> Option<Vec<char>> {
-        let mut result = vec![];
-        let dir = Direction::get_vector(direction);
-        let mut idx = Some(*start);
-        for _ in 0..*length {
-            result.push(*self.get(idx?)?);
-            idx = idx.unwrap() + dir;
-        }
-        Some(result)
-    }
-}
-
-impl Index<BoundVector2D> for CharGrid {
-    type Output = char;
-
-    fn index(&self, index: BoundVector2D) -> &Self::Output {
-        self.get(index).unwrap()
-    }
-}
-
-impl IndexMut<BoundVector2D> for CharGrid {
-    fn index_mut(&mut self, index: BoundVector2D) -> &mut Self::Output {
-        let idx = self.get_internal_index(index);
-        &mut self.grid[idx]
+    pub fn from_string(input: &str) -> Result<CharGrid, GridError> {
+        Grid::from_string_with(input, |c| c)
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use aoc_core::location::Location;
+
     use super::*;
 
+    use crate::{direction::Direction, vector::BoundVector2D};
+
     #[test]
     fn test_valid_input() {
         let input = "abc\ndef\nghi";
@@ -93,7 +23,7 @@ mod tests {
 
         assert_eq!(grid.rows, 3);
         assert_eq!(grid.cols, 3);
-        assert_eq!(grid.grid, vec!['a', 'b', 'c', 'd', 'e', 'f', 'g', 'h', 'i']);
+        assert_eq!(grid.cells(), vec!['a', 'b', 'c', 'd', 'e', 'f', 'g', 'h', 'i']);
 
         // Test specific grid values
         assert_eq!(
@@ -130,15 +60,30 @@ mod tests {
 
         assert_eq!(grid.rows, 0);
         assert_eq!(grid.cols, 0);
-        assert_eq!(grid.grid, vec![]);
+        assert_eq!(grid.cells(), vec![]);
     }
 
     #[test]
     fn test_inconsistent_row_lengths() {
         let input = "abc\ndefg\nhi";
         let result = CharGrid::from_string(input);
-        assert!(result.is_err());
-        assert_eq!(result.unwrap_err(), "All rows must have the same size.");
+        assert_eq!(
+            result.unwrap_err(),
+            GridError::RaggedRow {
+                location: Location::new(2, 4),
+                expected: 3,
+                found: 4
+            }
+        );
+    }
+
+    #[test]
+    fn test_short_row_message() {
+        let result = CharGrid::from_string("abc\nabc\nab");
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "line 3, column 3: expected a row of 3 characters, found 2"
+        );
     }
 
     #[test]
@@ -148,7 +93,7 @@ mod tests {
 
         assert_eq!(grid.rows, 1);
         assert_eq!(grid.cols, 6);
-        assert_eq!(grid.grid, vec!['a', 'b', 'c', 'd', 'e', 'f']);
+        assert_eq!(grid.cells(), vec!['a', 'b', 'c', 'd', 'e', 'f']);
 
         // Test specific grid values
         assert_eq!(
@@ -180,7 +125,7 @@ mod tests {
         let input = "abcdef";
         let grid = CharGrid::from_string(input).expect("Failed to create valid CharGrid");
 
-        grid[BoundVector2D::new(1, 0, grid.rows, grid.cols).unwrap()];
+        let _ = grid[BoundVector2D::new(1, 0, grid.rows, grid.cols).unwrap()];
     }
 
     #[test]
@@ -190,7 +135,7 @@ mod tests {
 
         assert_eq!(grid.rows, 4);
         assert_eq!(grid.cols, 1);
-        assert_eq!(grid.grid, vec!['a', 'b', 'c', 'd']);
+        assert_eq!(grid.cells(), vec!['a', 'b', 'c', 'd']);
 
         // Test specific grid values
         assert_eq!(
@@ -222,7 +167,7 @@ mod tests {
         let input = "a\nb\nc\nd";
         let grid = CharGrid::from_string(input).expect("Failed to create valid CharGrid");
 
-        grid[BoundVector2D::new(4, 0, grid.rows, grid.cols).unwrap()];
+        let _ = grid[BoundVector2D::new(4, 0, grid.rows, grid.cols).unwrap()];
     }
 
     #[test]
@@ -233,7 +178,7 @@ mod tests {
         assert_eq!(grid.rows, 3);
         assert_eq!(grid.cols, 5); // Spaces are treated as valid characters
         assert_eq!(
-            grid.grid,
+            grid.cells(),
             vec!['a', ' ', 'b', ' ', 'c', 'd', ' ', 'e', ' ', 'f', 'g', ' ', 'h', ' ', 'i']
         );
     }
@@ -274,41 +219,48 @@ mod tests {
     }
 
     #[test]
-    fn test_get_vector() {
+    fn test_ray() {
         let input = "a b\nc d";
         let grid = CharGrid::from_string(input).expect("Failed to create valid CharGrid");
-        let result = grid.get_vector_from_direction(
-            &BoundVector2D::new(0, 0, grid.rows, grid.cols).unwrap(),
-            &Direction::Forward,
-            &2,
-        );
-        assert_eq!(result, Some(vec!['a', ' ']));
+        let result: Vec<char> = grid
+            .ray(
+                BoundVector2D::new(0, 0, grid.rows, grid.cols).unwrap(),
+                &Direction::Forward,
+            )
+            .map(|(_, &c)| c)
+            .take(2)
+            .collect();
+        assert_eq!(result, vec!['a', ' ']);
     }
 
     #[test]
-    fn test_get_vector_2() {
+    fn test_ray_2() {
         let input = "abcd";
         let grid = CharGrid::from_string(input).expect("Failed to create valid CharGrid");
-        let result = grid.get_vector_from_direction(
-            &BoundVector2D::new(0, 0, grid.rows, grid.cols).unwrap(),
-            &Direction::Forward,
-            &4,
-        );
-        assert_eq!(result, Some(vec!['a', 'b', 'c', 'd']));
+        let result: Vec<char> = grid
+            .ray(
+                BoundVector2D::new(0, 0, grid.rows, grid.cols).unwrap(),
+                &Direction::Forward,
+            )
+            .map(|(_, &c)| c)
+            .collect();
+        assert_eq!(result, vec!['a', 'b', 'c', 'd']);
     }
 
     #[test]
-    fn test_get_vector_3() {
+    fn test_ray_3() {
         let input = "a\nb\nc\nd";
         let grid = CharGrid::from_string(input).expect("Failed to create valid CharGrid");
         assert_eq!(grid.rows, 4);
         assert_eq!(grid.cols, 1);
-        let result = grid.get_vector_from_direction(
-            &BoundVector2D::new(0, 0, grid.rows, grid.cols).unwrap(),
-            &Direction::Forward,
-            &4,
-        );
-        assert_eq!(result, None);
+        let result: Vec<char> = grid
+            .ray(
+                BoundVector2D::new(0, 0, grid.rows, grid.cols).unwrap(),
+                &Direction::Forward,
+            )
+            .map(|(_, &c)| c)
+            .collect();
+        assert_eq!(result, vec!['a']);
     }
 
     #[test]
@@ -316,10 +268,10 @@ mod tests {
         let input = "abcdef";
         let mut grid = CharGrid::from_string(input).expect("Failed to create valid CharGrid");
 
-        assert_eq!(grid.grid, vec!['a', 'b', 'c', 'd', 'e', 'f']);
+        assert_eq!(grid.cells(), vec!['a', 'b', 'c', 'd', 'e', 'f']);
         let result = grid.set(BoundVector2D::new(0, 3, grid.rows, grid.cols).unwrap(), 'e');
         assert_eq!(result, Some(()));
-        assert_eq!(grid.grid, vec!['a', 'b', 'c', 'e', 'e', 'f']);
+        assert_eq!(grid.cells(), vec!['a', 'b', 'c', 'e', 'e', 'f']);
     }
     #[test]
     fn test_set_invalid() {
@@ -331,6 +283,6 @@ mod tests {
             'e',
         );
         assert_eq!(result, None);
-        assert_eq!(grid.grid, vec!['a', 'b', 'c', 'd', 'e', 'f']);
+        assert_eq!(grid.cells(), vec!['a', 'b', 'c', 'd', 'e', 'f']);
     }
 }
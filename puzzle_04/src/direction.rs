@@ -10,6 +10,18 @@ pub enum Direction {
     Downbackward,
 }
 
+// clockwise, starting up
+const CLOCKWISE: [Direction; 8] = [
+    Direction::Up,
+    Direction::Upforward,
+    Direction::Forward,
+    Direction::Downforward,
+    Direction::Down,
+    Direction::Downbackward,
+    Direction::Backward,
+    Direction::Upbackward,
+];
+
 impl Direction {
     pub fn get_vector(&self) -> (isize, isize) {
         match self {
@@ -24,16 +36,277 @@ impl Direction {
         }
     }
 
-    pub fn variants() -> Vec<Self> {
-        vec![
-            Self::Up,
+    pub fn from_vector(vector: (isize, isize)) -> Option<Self> {
+        Self::variants()
+            .iter()
+            .find(|d| d.get_vector() == vector)
+            .cloned()
+    }
+
+    // clockwise, starting up
+    pub fn variants() -> &'static [Self] {
+        &CLOCKWISE
+    }
+
+    // the four orthogonal directions, clockwise from up
+    pub fn cardinals() -> &'static [Self] {
+        &[Self::Up, Self::Forward, Self::Down, Self::Backward]
+    }
+
+    pub fn diagonals() -> &'static [Self] {
+        &[
             Self::Upforward,
-            Self::Forward,
             Self::Downforward,
-            Self::Down,
             Self::Downbackward,
-            Self::Backward,
             Self::Upbackward,
         ]
     }
+
+    pub fn is_cardinal(&self) -> bool {
+        matches!(self, Self::Up | Self::Forward | Self::Down | Self::Backward)
+    }
+
+    pub fn is_diagonal(&self) -> bool {
+        !self.is_cardinal()
+    }
+
+    // the position in CLOCKWISE
+    fn eighths(&self) -> usize {
+        match self {
+            Self::Up => 0,
+            Self::Upforward => 1,
+            Self::Forward => 2,
+            Self::Downforward => 3,
+            Self::Down => 4,
+            Self::Downbackward => 5,
+            Self::Backward => 6,
+            Self::Upbackward => 7,
+        }
+    }
+
+    // eighths of a full turn, clockwise
+    fn rotate(&self, eighths: usize) -> Self {
+        CLOCKWISE[(self.eighths() + eighths) % 8].clone()
+    }
+
+    pub fn turn_right(&self) -> Self {
+        self.rotate(2)
+    }
+
+    pub fn turn_left(&self) -> Self {
+        self.rotate(6)
+    }
+
+    pub fn turn_right_45(&self) -> Self {
+        self.rotate(1)
+    }
+
+    pub fn turn_left_45(&self) -> Self {
+        self.rotate(7)
+    }
+
+    pub fn opposite(&self) -> Self {
+        self.rotate(4)
+    }
+
+    fn reflect(&self, f: impl Fn(isize, isize) -> (isize, isize)) -> Self {
+        let (x, y) = self.get_vector();
+        Self::from_vector(f(x, y)).unwrap()
+    }
+
+    // mirrored at a horizontal line, up becomes down
+    pub fn reflect_horizontal(&self) -> Self {
+        self.reflect(|x, y| (-x, y))
+    }
+
+    // mirrored at a vertical line, forward becomes backward
+    pub fn reflect_vertical(&self) -> Self {
+        self.reflect(|x, y| (x, -y))
+    }
+
+    // bounced off a '/' mirror, moving forward turns up
+    pub fn reflect_slash(&self) -> Self {
+        self.reflect(|x, y| (-y, -x))
+    }
+
+    // bounced off a '\' mirror, moving forward turns down
+    pub fn reflect_backslash(&self) -> Self {
+        self.reflect(|x, y| (y, x))
+    }
+
+    pub fn from_arrow(c: char) -> Option<Self> {
+        match c {
+            '^' => Some(Self::Up),
+            '>' => Some(Self::Forward),
+            'v' => Some(Self::Down),
+            '<' => Some(Self::Backward),
+            _ => None,
+        }
+    }
+
+    pub fn from_letter(c: char) -> Option<Self> {
+        match c {
+            'U' => Some(Self::Up),
+            'R' => Some(Self::Forward),
+            'D' => Some(Self::Down),
+            'L' => Some(Self::Backward),
+            _ => None,
+        }
+    }
+
+    pub fn from_compass(c: char) -> Option<Self> {
+        match c {
+            'N' => Some(Self::Up),
+            'E' => Some(Self::Forward),
+            'S' => Some(Self::Down),
+            'W' => Some(Self::Backward),
+            _ => None,
+        }
+    }
+
+    // any of ^>v<, UDLR or NESW
+    pub fn from_char(c: char) -> Option<Self> {
+        Self::from_arrow(c)
+            .or_else(|| Self::from_letter(c))
+            .or_else(|| Self::from_compass(c))
+    }
+
+    pub fn to_arrow(&self) -> Option<char> {
+        match self {
+            Self::Up => Some('^'),
+            Self::Forward => Some('>'),
+            Self::Down => Some('v'),
+            Self::Backward => Some('<'),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_turn_right() {
+        let mut direction = Direction::Up;
+        let mut seen = vec![];
+        for _ in 0..4 {
+            direction = direction.turn_right();
+            seen.push(direction.clone());
+        }
+        assert_eq!(
+            seen,
+            vec![
+                Direction::Forward,
+                Direction::Down,
+                Direction::Backward,
+                Direction::Up
+            ]
+        );
+    }
+
+    #[test]
+    fn test_turns_on_diagonals() {
+        assert_eq!(Direction::Upforward.turn_right(), Direction::Downforward);
+        assert_eq!(Direction::Upforward.turn_left(), Direction::Upbackward);
+        assert_eq!(Direction::Downbackward.opposite(), Direction::Upforward);
+    }
+
+    #[test]
+    fn test_turn_45() {
+        assert_eq!(Direction::Up.turn_right_45(), Direction::Upforward);
+        assert_eq!(Direction::Up.turn_left_45(), Direction::Upbackward);
+        for d in Direction::variants() {
+            assert_eq!(d.turn_right_45().turn_left_45(), *d);
+            assert_eq!(d.turn_right_45().turn_right_45(), d.turn_right());
+            assert_eq!(d.turn_left().turn_left(), d.opposite());
+        }
+    }
+
+    #[test]
+    fn test_eighths_match_clockwise_order() {
+        for (i, d) in CLOCKWISE.iter().enumerate() {
+            assert_eq!(d.eighths(), i);
+        }
+    }
+
+    #[test]
+    fn test_opposite_vector() {
+        for d in Direction::variants() {
+            let (x, y) = d.get_vector();
+            assert_eq!(d.opposite().get_vector(), (-x, -y));
+        }
+    }
+
+    #[test]
+    fn test_reflections() {
+        assert_eq!(Direction::Up.reflect_horizontal(), Direction::Down);
+        assert_eq!(Direction::Forward.reflect_horizontal(), Direction::Forward);
+        assert_eq!(
+            Direction::Upforward.reflect_vertical(),
+            Direction::Upbackward
+        );
+        assert_eq!(Direction::Forward.reflect_slash(), Direction::Up);
+        assert_eq!(Direction::Down.reflect_slash(), Direction::Backward);
+        assert_eq!(Direction::Forward.reflect_backslash(), Direction::Down);
+        assert_eq!(Direction::Up.reflect_backslash(), Direction::Backward);
+        for d in Direction::variants() {
+            assert_eq!(d.reflect_slash().reflect_slash(), *d);
+            assert_eq!(d.reflect_backslash().reflect_backslash(), *d);
+        }
+    }
+
+    #[test]
+    fn test_subsets() {
+        assert!(Direction::cardinals().iter().all(Direction::is_cardinal));
+        assert!(Direction::diagonals().iter().all(Direction::is_diagonal));
+        assert_eq!(
+            Direction::cardinals().len() + Direction::diagonals().len(),
+            Direction::variants().len()
+        );
+    }
+
+    #[test]
+    fn test_parse() {
+        let parsed: Vec<_> = "^>v<".chars().map(Direction::from_char).collect();
+        assert_eq!(
+            parsed,
+            "URDL".chars().map(Direction::from_char).collect::<Vec<_>>()
+        );
+        assert_eq!(
+            parsed,
+            "NESW".chars().map(Direction::from_char).collect::<Vec<_>>()
+        );
+        assert_eq!(
+            parsed,
+            Direction::cardinals()
+                .iter()
+                .cloned()
+                .map(Some)
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(Direction::from_char('x'), None);
+        assert_eq!(Direction::from_arrow('U'), None);
+        assert_eq!(Direction::from_letter('E'), None);
+    }
+
+    #[test]
+    fn test_to_arrow() {
+        for d in Direction::cardinals() {
+            assert_eq!(
+                d.to_arrow().and_then(Direction::from_arrow),
+                Some(d.clone())
+            );
+        }
+        assert_eq!(Direction::Upforward.to_arrow(), None);
+    }
+
+    #[test]
+    fn test_from_vector() {
+        assert_eq!(
+            Direction::from_vector((1, -1)),
+            Some(Direction::Downbackward)
+        );
+        assert_eq!(Direction::from_vector((2, 0)), None);
+    }
 }
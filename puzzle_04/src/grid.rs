@@ -0,0 +1,601 @@
+use std::{
+    error::Error,
+    fmt::Display,
+    iter,
+    ops::{Index, IndexMut},
+};
+
+use aoc_core::location::Location;
+
+use crate::{
+    direction::Direction,
+    vector::{BoundVector2D, Vector2D},
+};
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum GridError {
+    RaggedRow {
+        location: Location,
+        expected: usize,
+        found: usize,
+    },
+    UnexpectedChar {
+        location: Location,
+        found: char,
+    },
+}
+
+impl Display for GridError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::RaggedRow {
+                location,
+                expected,
+                found,
+            } => write!(
+                f,
+                "{}: expected a row of {} characters, found {}",
+                location, expected, found
+            ),
+            Self::UnexpectedChar { location, found } => {
+                write!(f, "{}: unexpected character '{}'", location, found)
+            }
+        }
+    }
+}
+
+impl Error for GridError {}
+
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    pub rows: usize,
+    pub cols: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(rows: usize, cols: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            rows,
+            cols,
+            cells: vec![value; rows * cols],
+        }
+    }
+
+    // every cell computed from its index, row by row
+    pub fn from_fn(rows: usize, cols: usize, mut f: impl FnMut(BoundVector2D) -> T) -> Self {
+        let cells = (0..rows)
+            .flat_map(|x| (0..cols).map(move |y| BoundVector2D::new(x, y, rows, cols).unwrap()))
+            .map(&mut f)
+            .collect();
+        Self { rows, cols, cells }
+    }
+
+    // reads the same layout as CharGrid::from_string and decodes every character once
+    pub fn from_string_with(
+        input: &str,
+        mut decode: impl FnMut(char) -> T,
+    ) -> Result<Self, GridError> {
+        Self::try_from_string_with(input, |c| Some(decode(c)))
+    }
+
+    // like from_string_with, but a character decode returns None for fails at its location
+    pub fn try_from_string_with(
+        input: &str,
+        mut decode: impl FnMut(char) -> Option<T>,
+    ) -> Result<Self, GridError> {
+        let rows_vec: Vec<_> = input.lines().collect();
+        if rows_vec.is_empty() {
+            return Ok(Self {
+                rows: 0,
+                cols: 0,
+                cells: vec![],
+            });
+        }
+
+        let cols = rows_vec[0].chars().count();
+        if let Some((i, row)) = rows_vec
+            .iter()
+            .enumerate()
+            .find(|(_, row)| row.chars().count() != cols)
+        {
+            let found = row.chars().count();
+            return Err(GridError::RaggedRow {
+                location: Location::new(i + 1, found.min(cols) + 1),
+                expected: cols,
+                found,
+            });
+        }
+
+        let mut cells = Vec::with_capacity(rows_vec.len() * cols);
+        for (i, row) in rows_vec.iter().enumerate() {
+            for (j, c) in row.chars().enumerate() {
+                cells.push(decode(c).ok_or(GridError::UnexpectedChar {
+                    location: Location::new(i + 1, j + 1),
+                    found: c,
+                })?);
+            }
+        }
+
+        Ok(Self {
+            rows: rows_vec.len(),
+            cols,
+            cells,
+        })
+    }
+
+    // the cells row by row
+    pub fn cells(&self) -> &[T] {
+        &self.cells
+    }
+
+    // the position as an index of this grid, None if it lies off the grid
+    pub fn bound(&self, pos: impl Into<Vector2D>) -> Option<BoundVector2D> {
+        pos.into().bound(self.rows, self.cols)
+    }
+
+    // the position on this grid seen as a torus, None if the grid has no cells
+    pub fn wrap(&self, pos: impl Into<Vector2D>) -> Option<BoundVector2D> {
+        pos.into().wrap(self.rows, self.cols)
+    }
+
+    fn get_internal_index(&self, index: BoundVector2D) -> usize {
+        self.cols * index.x + index.y
+    }
+
+    pub fn get(&self, index: BoundVector2D) -> Option<&T> {
+        let idx = self.bound(index)?;
+        self.cells.get(self.get_internal_index(idx))
+    }
+
+    pub fn get_mut(&mut self, index: BoundVector2D) -> Option<&mut T> {
+        let idx = self.bound(index)?;
+        let index = self.get_internal_index(idx);
+        self.cells.get_mut(index)
+    }
+
+    pub fn set(&mut self, index: BoundVector2D, value: T) -> Option<()> {
+        *self.get_mut(index)? = value;
+        Some(())
+    }
+
+    pub fn iter_indices(&self) -> impl Iterator<Item = BoundVector2D> + '_ {
+        (0..self.rows).flat_map(move |x| {
+            (0..self.cols).map(move |y| BoundVector2D::new(x, y, self.rows, self.cols).unwrap())
+        })
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (BoundVector2D, &T)> + '_ {
+        self.iter_indices().zip(&self.cells)
+    }
+
+    pub fn position(&self, mut predicate: impl FnMut(&T) -> bool) -> Option<BoundVector2D> {
+        self.iter()
+            .find(|(_, value)| predicate(value))
+            .map(|(idx, _)| idx)
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            rows: self.rows,
+            cols: self.cols,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    fn neighbors_in<'a, D>(
+        &'a self,
+        idx: BoundVector2D,
+        directions: D,
+    ) -> impl Iterator<Item = (Direction, Option<(BoundVector2D, &'a T)>)> + 'a
+    where
+        D: IntoIterator<Item = Direction>,
+        D::IntoIter: 'a,
+    {
+        directions.into_iter().map(move |dir| {
+            let neighbor = (idx + dir.get_vector())
+                .and_then(|n| self.bound(n))
+                .and_then(|n| Some((n, self.get(n)?)));
+            (dir, neighbor)
+        })
+    }
+
+    pub fn neighbors4(
+        &self,
+        idx: BoundVector2D,
+    ) -> impl Iterator<Item = (Direction, BoundVector2D, &T)> + '_ {
+        self.neighbors_in(idx, Direction::cardinals().iter().cloned())
+            .filter_map(|(dir, n)| n.map(|(n, value)| (dir, n, value)))
+    }
+
+    pub fn neighbors8(
+        &self,
+        idx: BoundVector2D,
+    ) -> impl Iterator<Item = (Direction, BoundVector2D, &T)> + '_ {
+        self.neighbors_in(idx, Direction::variants().iter().cloned())
+            .filter_map(|(dir, n)| n.map(|(n, value)| (dir, n, value)))
+    }
+
+    // also yields the directions leaving the grid, with None in place of the neighbor
+    pub fn neighbors4_with_edges(
+        &self,
+        idx: BoundVector2D,
+    ) -> impl Iterator<Item = (Direction, Option<(BoundVector2D, &T)>)> + '_ {
+        self.neighbors_in(idx, Direction::cardinals().iter().cloned())
+    }
+
+    pub fn neighbors8_with_edges(
+        &self,
+        idx: BoundVector2D,
+    ) -> impl Iterator<Item = (Direction, Option<(BoundVector2D, &T)>)> + '_ {
+        self.neighbors_in(idx, Direction::variants().iter().cloned())
+    }
+
+    fn wrapping_neighbors_in<'a, D>(
+        &'a self,
+        idx: BoundVector2D,
+        directions: D,
+    ) -> impl Iterator<Item = (Direction, BoundVector2D, &'a T)> + 'a
+    where
+        D: IntoIterator<Item = Direction>,
+        D::IntoIter: 'a,
+    {
+        let idx = self.bound(idx);
+        directions.into_iter().filter_map(move |dir| {
+            let n = idx?.wrapping_add(dir.get_vector().into());
+            Some((dir, n, &self[n]))
+        })
+    }
+
+    // the grid as a torus, neighbors across an edge come from the opposite side
+    pub fn neighbors4_wrapping(
+        &self,
+        idx: BoundVector2D,
+    ) -> impl Iterator<Item = (Direction, BoundVector2D, &T)> + '_ {
+        self.wrapping_neighbors_in(idx, Direction::cardinals().iter().cloned())
+    }
+
+    pub fn neighbors8_wrapping(
+        &self,
+        idx: BoundVector2D,
+    ) -> impl Iterator<Item = (Direction, BoundVector2D, &T)> + '_ {
+        self.wrapping_neighbors_in(idx, Direction::variants().iter().cloned())
+    }
+
+    // the cells from start (included) in the direction until the edge of the grid
+    pub fn ray(
+        &self,
+        start: BoundVector2D,
+        direction: &Direction,
+    ) -> impl Iterator<Item = (BoundVector2D, &T)> + '_ {
+        self.ray_step(start, direction.get_vector())
+    }
+
+    // like ray, moving by any offset, a zero step only yields the start
+    pub fn ray_step(
+        &self,
+        start: BoundVector2D,
+        step: (isize, isize),
+    ) -> impl Iterator<Item = (BoundVector2D, &T)> + '_ {
+        let first = self.bound(start);
+        iter::successors(
+            first,
+            move |&idx| {
+                if step == (0, 0) {
+                    None
+                } else {
+                    idx + step
+                }
+            },
+        )
+        .map(|idx| (idx, &self[idx]))
+    }
+
+    // like ray, wrapping around the edges, stops before it would reach start again
+    pub fn ray_wrapping(
+        &self,
+        start: BoundVector2D,
+        direction: &Direction,
+    ) -> impl Iterator<Item = (BoundVector2D, &T)> + '_ {
+        self.ray_step_wrapping(start, direction.get_vector())
+    }
+
+    pub fn ray_step_wrapping(
+        &self,
+        start: BoundVector2D,
+        step: (isize, isize),
+    ) -> impl Iterator<Item = (BoundVector2D, &T)> + '_ {
+        let first = self.bound(start);
+        iter::successors(first, move |&idx| {
+            let next = idx.wrapping_add(Vector2D::from(step));
+            (Some(next) != first).then_some(next)
+        })
+        .map(|idx| (idx, &self[idx]))
+    }
+}
+
+impl<T> Index<BoundVector2D> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, index: BoundVector2D) -> &Self::Output {
+        self.get(index).unwrap()
+    }
+}
+
+impl<T> IndexMut<BoundVector2D> for Grid<T> {
+    fn index_mut(&mut self, index: BoundVector2D) -> &mut Self::Output {
+        let idx = self.get_internal_index(index);
+        &mut self.cells[idx]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn idx(x: usize, y: usize) -> BoundVector2D {
+        BoundVector2D::new(x, y, 2, 3).unwrap()
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    enum Cell {
+        Open,
+        Wall,
+    }
+
+    #[test]
+    fn test_new() {
+        let grid = Grid::new(2, 3, 0u8);
+        assert_eq!(grid.rows, 2);
+        assert_eq!(grid.cols, 3);
+        assert_eq!(grid.cells(), &[0; 6]);
+    }
+
+    #[test]
+    fn test_from_fn() {
+        let grid = Grid::from_fn(2, 3, |i| i.x * 10 + i.y);
+        assert_eq!(grid.cells(), &[0, 1, 2, 10, 11, 12]);
+        assert_eq!(Grid::from_fn(0, 3, |_| 0).cells(), &[] as &[i32]);
+    }
+
+    #[test]
+    fn test_from_string_with() {
+        let grid = Grid::from_string_with("#..\n.#.", |c| c == '#').unwrap();
+        assert_eq!(grid.cells(), &[true, false, false, false, true, false]);
+        assert!(grid[idx(1, 1)]);
+    }
+
+    #[test]
+    fn test_from_string_with_ragged_row() {
+        let result = Grid::from_string_with("12\n3", |c| c.to_digit(10));
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "line 2, column 2: expected a row of 2 characters, found 1"
+        );
+    }
+
+    #[test]
+    fn test_bound() {
+        let grid = Grid::new(2, 3, 0);
+        assert_eq!(grid.bound(Vector2D::new(1, 2)), Some(idx(1, 2)));
+        assert_eq!(grid.bound(Vector2D::new(2, 1)), None);
+        assert_eq!(grid.bound((-1, 0)), None);
+        let other = BoundVector2D::new(1, 1, 5, 5).unwrap();
+        assert_eq!(grid.bound(other), Some(idx(1, 1)));
+    }
+
+    #[test]
+    fn test_wrap() {
+        let grid = Grid::new(2, 3, 0);
+        assert_eq!(grid.wrap(Vector2D::new(-1, 4)), Some(idx(1, 1)));
+        assert_eq!(Grid::new(0, 3, 0).wrap((0, 0)), None);
+    }
+
+    #[test]
+    fn test_try_from_string_with() {
+        let grid = Grid::try_from_string_with("12\n34", |c| c.to_digit(10)).unwrap();
+        assert_eq!(grid.cells(), &[1, 2, 3, 4]);
+        let result = Grid::try_from_string_with("12\n3x", |c| c.to_digit(10));
+        assert_eq!(
+            result,
+            Err(GridError::UnexpectedChar {
+                location: Location::new(2, 2),
+                found: 'x'
+            })
+        );
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "line 2, column 2: unexpected character 'x'"
+        );
+    }
+
+    #[test]
+    fn test_map_to_digits() {
+        let grid = Grid::from_string_with("012\n9.8", |c| c).unwrap();
+        let digits = grid.map(|c| c.to_digit(10));
+        assert_eq!(digits.get(idx(0, 2)), Some(&Some(2)));
+        assert_eq!(digits.get(idx(1, 1)), Some(&None));
+        assert_eq!(digits.rows, 2);
+        assert_eq!(digits.cols, 3);
+    }
+
+    #[test]
+    fn test_map_to_enum() {
+        let grid = Grid::from_string_with("#..\n..#", |c| c).unwrap();
+        let cells = grid.map(|&c| if c == '#' { Cell::Wall } else { Cell::Open });
+        assert_eq!(cells.iter().filter(|(_, &c)| c == Cell::Wall).count(), 2);
+        assert_eq!(cells.position(|&c| c == Cell::Wall), Some(idx(0, 0)));
+    }
+
+    #[test]
+    fn test_get_out_of_bounds() {
+        let grid = Grid::new(2, 3, 'a');
+        assert_eq!(grid.get(BoundVector2D::new(2, 0, 3, 3).unwrap()), None);
+    }
+
+    #[test]
+    fn test_set_and_index_mut() {
+        let mut grid = Grid::new(2, 3, 0);
+        assert_eq!(grid.set(idx(1, 2), 5), Some(()));
+        grid[idx(0, 1)] += 3;
+        *grid.get_mut(idx(0, 1)).unwrap() *= 2;
+        assert_eq!(grid.cells(), &[0, 6, 0, 0, 0, 5]);
+        assert_eq!(grid.set(BoundVector2D::new(2, 0, 3, 3).unwrap(), 1), None);
+    }
+
+    #[test]
+    fn test_neighbors4() {
+        let grid = Grid::from_string_with("abc\ndef", |c| c).unwrap();
+        let neighbors: Vec<_> = grid
+            .neighbors4(idx(0, 1))
+            .map(|(dir, n, &c)| (dir, n.x, n.y, c))
+            .collect();
+        assert_eq!(
+            neighbors,
+            vec![
+                (Direction::Forward, 0, 2, 'c'),
+                (Direction::Down, 1, 1, 'e'),
+                (Direction::Backward, 0, 0, 'a')
+            ]
+        );
+    }
+
+    #[test]
+    fn test_neighbors8() {
+        let grid = Grid::from_string_with("abc\ndef", |c| c).unwrap();
+        let neighbors: String = grid.neighbors8(idx(1, 2)).map(|(_, _, &c)| c).collect();
+        assert_eq!(neighbors, "ceb");
+        assert_eq!(grid.neighbors8(idx(0, 1)).count(), 5);
+    }
+
+    #[test]
+    fn test_neighbors_with_edges() {
+        let grid = Grid::new(2, 3, 0);
+        let edges: Vec<_> = grid
+            .neighbors4_with_edges(idx(0, 0))
+            .filter(|(_, n)| n.is_none())
+            .map(|(dir, _)| dir)
+            .collect();
+        assert_eq!(edges, vec![Direction::Up, Direction::Backward]);
+        assert_eq!(grid.neighbors8_with_edges(idx(1, 1)).count(), 8);
+        assert_eq!(
+            grid.neighbors8_with_edges(idx(1, 1))
+                .filter(|(_, n)| n.is_some())
+                .count(),
+            5
+        );
+    }
+
+    #[test]
+    fn test_neighbors_use_grid_bounds() {
+        let grid = Grid::new(2, 2, 0);
+        let idx = BoundVector2D::new(1, 1, 5, 5).unwrap();
+        assert_eq!(grid.neighbors4(idx).count(), 2);
+    }
+
+    #[test]
+    fn test_ray() {
+        let grid = Grid::from_string_with("abc\ndef", |c| c).unwrap();
+        let ray: String = grid
+            .ray(idx(1, 0), &Direction::Forward)
+            .map(|(_, &c)| c)
+            .collect();
+        assert_eq!(ray, "def");
+        let ray: Vec<_> = grid
+            .ray(idx(1, 0), &Direction::Upforward)
+            .map(|(i, &c)| (i.x, i.y, c))
+            .collect();
+        assert_eq!(ray, vec![(1, 0, 'd'), (0, 1, 'b')]);
+    }
+
+    #[test]
+    fn test_ray_from_outside() {
+        let grid = Grid::new(2, 3, 0);
+        let start = BoundVector2D::new(2, 0, 3, 3).unwrap();
+        assert_eq!(grid.ray(start, &Direction::Up).count(), 0);
+    }
+
+    #[test]
+    fn test_ray_step() {
+        let grid = Grid::from_string_with("abcde\nfghij\nklmno", |c| c).unwrap();
+        let start = BoundVector2D::new(0, 0, 3, 5).unwrap();
+        let ray: String = grid.ray_step(start, (1, 2)).map(|(_, &c)| c).collect();
+        assert_eq!(ray, "aho");
+        let ray: String = grid.ray_step(start, (0, 0)).map(|(_, &c)| c).collect();
+        assert_eq!(ray, "a");
+    }
+
+    #[test]
+    fn test_neighbors4_wrapping() {
+        let grid = Grid::from_string_with("abc\ndef", |c| c).unwrap();
+        let neighbors: Vec<_> = grid
+            .neighbors4_wrapping(idx(0, 0))
+            .map(|(dir, n, &c)| (dir, n.x, n.y, c))
+            .collect();
+        assert_eq!(
+            neighbors,
+            vec![
+                (Direction::Up, 1, 0, 'd'),
+                (Direction::Forward, 0, 1, 'b'),
+                (Direction::Down, 1, 0, 'd'),
+                (Direction::Backward, 0, 2, 'c')
+            ]
+        );
+    }
+
+    #[test]
+    fn test_neighbors8_wrapping() {
+        let grid = Grid::from_string_with("abc\ndef", |c| c).unwrap();
+        let neighbors: String = grid
+            .neighbors8_wrapping(idx(1, 2))
+            .map(|(_, _, &c)| c)
+            .collect();
+        assert_eq!(neighbors, "cadacbeb");
+        let outside = BoundVector2D::new(2, 0, 3, 3).unwrap();
+        assert_eq!(grid.neighbors8_wrapping(outside).count(), 0);
+    }
+
+    #[test]
+    fn test_ray_wrapping() {
+        let grid = Grid::from_string_with("abc\ndef", |c| c).unwrap();
+        let ray: String = grid
+            .ray_wrapping(idx(0, 1), &Direction::Forward)
+            .map(|(_, &c)| c)
+            .collect();
+        assert_eq!(ray, "bca");
+        let ray: String = grid
+            .ray_wrapping(idx(0, 0), &Direction::Downforward)
+            .map(|(_, &c)| c)
+            .collect();
+        assert_eq!(ray, "aecdbf");
+    }
+
+    #[test]
+    fn test_ray_step_wrapping() {
+        let grid = Grid::from_string_with("abcde\nfghij\nklmno", |c| c).unwrap();
+        let start = BoundVector2D::new(0, 0, 3, 5).unwrap();
+        let ray: String = grid
+            .ray_step_wrapping(start, (0, 2))
+            .map(|(_, &c)| c)
+            .collect();
+        assert_eq!(ray, "acebd");
+        let ray: String = grid
+            .ray_step_wrapping(start, (0, 0))
+            .map(|(_, &c)| c)
+            .collect();
+        assert_eq!(ray, "a");
+    }
+
+    #[test]
+    fn test_iter() {
+        let grid = Grid::from_string_with("ab\ncd", |c| c).unwrap();
+        let cells: Vec<_> = grid.iter().map(|(i, &c)| (i.x, i.y, c)).collect();
+        assert_eq!(
+            cells,
+            vec![(0, 0, 'a'), (0, 1, 'b'), (1, 0, 'c'), (1, 1, 'd')]
+        );
+    }
+}
@@ -0,0 +1,165 @@
+use std::{collections::HashMap, fs, io, path::Path};
+
+use crate::char_grid::CharGrid;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rgb(pub u8, pub u8, pub u8);
+
+impl Rgb {
+    pub const BLACK: Rgb = Rgb(0, 0, 0);
+    pub const WHITE: Rgb = Rgb(255, 255, 255);
+}
+
+// the color of each character, anything not listed gets the default
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Palette {
+    colors: HashMap<char, Rgb>,
+    default: Rgb,
+}
+
+impl Palette {
+    pub fn new(default: Rgb) -> Self {
+        Self {
+            colors: HashMap::new(),
+            default,
+        }
+    }
+
+    pub fn with(mut self, c: char, color: Rgb) -> Self {
+        self.colors.insert(c, color);
+        self
+    }
+
+    pub fn color(&self, c: char) -> Rgb {
+        self.colors.get(&c).copied().unwrap_or(self.default)
+    }
+}
+
+// empty cells black, everything else white
+impl Default for Palette {
+    fn default() -> Self {
+        Self::new(Rgb::WHITE).with('.', Rgb::BLACK)
+    }
+}
+
+// pixels row by row, every grid cell drawn as a scale x scale square
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Image {
+    pub width: usize,
+    pub height: usize,
+    pixels: Vec<Rgb>,
+}
+
+impl Image {
+    pub fn from_grid(grid: &CharGrid, palette: &Palette, scale: usize) -> Self {
+        let width = grid.cols * scale;
+        let height = grid.rows * scale;
+        let pixels = (0..height)
+            .flat_map(|py| (0..width).map(move |px| (py / scale, px / scale)))
+            .map(|(x, y)| palette.color(grid.cells()[x * grid.cols + y]))
+            .collect();
+        Self {
+            width,
+            height,
+            pixels,
+        }
+    }
+
+    pub fn pixels(&self) -> &[Rgb] {
+        &self.pixels
+    }
+
+    fn bytes(&self) -> impl Iterator<Item = u8> + '_ {
+        self.pixels.iter().flat_map(|&Rgb(r, g, b)| [r, g, b])
+    }
+
+    // binary PPM (P6), readable by most image viewers without any dependency
+    pub fn to_ppm(&self) -> Vec<u8> {
+        let mut ppm = format!("P6\n{} {}\n255\n", self.width, self.height).into_bytes();
+        ppm.extend(self.bytes());
+        ppm
+    }
+
+    pub fn write_ppm(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        fs::write(path, self.to_ppm())
+    }
+
+    #[cfg(feature = "png")]
+    pub fn to_png(&self) -> Result<Vec<u8>, png::EncodingError> {
+        let mut png = vec![];
+        let mut encoder = png::Encoder::new(&mut png, self.width as u32, self.height as u32);
+        encoder.set_color(png::ColorType::Rgb);
+        encoder.set_depth(png::BitDepth::Eight);
+        let mut writer = encoder.write_header()?;
+        writer.write_image_data(&self.bytes().collect::<Vec<_>>())?;
+        writer.finish()?;
+        Ok(png)
+    }
+
+    #[cfg(feature = "png")]
+    pub fn write_png(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        fs::write(path, self.to_png().map_err(io::Error::other)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RED: Rgb = Rgb(255, 0, 0);
+
+    #[test]
+    fn test_palette() {
+        let palette = Palette::new(Rgb::BLACK).with('#', RED);
+        assert_eq!(palette.color('#'), RED);
+        assert_eq!(palette.color('x'), Rgb::BLACK);
+        assert_eq!(Palette::default().color('.'), Rgb::BLACK);
+        assert_eq!(Palette::default().color('#'), Rgb::WHITE);
+    }
+
+    #[test]
+    fn test_from_grid() {
+        let grid = CharGrid::from_string("#.\n..\n.#").unwrap();
+        let image = Image::from_grid(&grid, &Palette::default(), 1);
+        assert_eq!((image.width, image.height), (2, 3));
+        let lit: Vec<_> = image.pixels().iter().map(|&p| p == Rgb::WHITE).collect();
+        assert_eq!(lit, vec![true, false, false, false, false, true]);
+    }
+
+    #[test]
+    fn test_from_grid_scaled() {
+        let grid = CharGrid::from_string("#.").unwrap();
+        let image = Image::from_grid(&grid, &Palette::default(), 2);
+        assert_eq!((image.width, image.height), (4, 2));
+        let w = Rgb::WHITE;
+        let b = Rgb::BLACK;
+        assert_eq!(image.pixels(), &[w, w, b, b, w, w, b, b]);
+    }
+
+    #[test]
+    fn test_to_ppm() {
+        let grid = CharGrid::from_string("#.").unwrap();
+        let palette = Palette::new(Rgb::BLACK).with('#', RED);
+        let ppm = Image::from_grid(&grid, &palette, 1).to_ppm();
+        let mut expected = b"P6\n2 1\n255\n".to_vec();
+        expected.extend([255, 0, 0, 0, 0, 0]);
+        assert_eq!(ppm, expected);
+    }
+
+    #[test]
+    fn test_empty_ppm() {
+        let grid = CharGrid::from_string("").unwrap();
+        let ppm = Image::from_grid(&grid, &Palette::default(), 3).to_ppm();
+        assert_eq!(ppm, b"P6\n0 0\n255\n");
+    }
+
+    #[cfg(feature = "png")]
+    #[test]
+    fn test_to_png() {
+        let grid = CharGrid::from_string("#.\n.#").unwrap();
+        let png = Image::from_grid(&grid, &Palette::default(), 2)
+            .to_png()
+            .unwrap();
+        assert_eq!(&png[..8], b"\x89PNG\r\n\x1a\n");
+    }
+}
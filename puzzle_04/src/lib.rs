@@ -1,3 +1,69 @@
+use std::fmt::Display;
+
+use aoc_core::Solution;
+use char_grid::{CharGrid, GridError};
+use word_search::{count_str, count_x_shape};
+
 pub mod char_grid;
 pub mod direction;
+pub mod grid;
+pub mod image;
+pub mod path;
+pub mod pattern;
+pub mod region;
+pub mod render;
+pub mod simulation;
+pub mod sparse;
 pub mod vector;
+pub mod view;
+pub mod word_search;
+
+pub type Input = CharGrid;
+
+pub struct Puzzle04;
+
+pub fn parse(input: &str) -> Result<Input, GridError> {
+    CharGrid::from_string(input)
+}
+
+pub fn part1(grid: &Input) -> usize {
+    count_str("XMAS", grid)
+}
+
+pub fn part2(grid: &Input) -> usize {
+    count_x_shape("MAS", grid)
+}
+
+impl Solution for Puzzle04 {
+    const DAY: u8 = 4;
+    const TITLE: &'static str = "Ceres Search";
+
+    type Input = Input;
+    type Error = GridError;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        parse(input)
+    }
+
+    fn part_1(input: &Self::Input) -> impl Display {
+        part1(input)
+    }
+
+    fn part_2(input: &Self::Input) -> impl Display {
+        part2(input)
+    }
+}
+
+aoc_core::fixture_tests!(Puzzle04);
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::answers::assert_answers;
+
+    use super::*;
+
+    #[test]
+    fn test_main() {
+        assert_answers::<Puzzle04>();
+    }
+}
@@ -0,0 +1,502 @@
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap, HashSet, VecDeque},
+    hash::Hash,
+};
+
+use crate::{direction::Direction, grid::Grid, vector::BoundVector2D};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Path<S> {
+    pub distance: usize,
+    // from the start to the goal, both included
+    pub states: Vec<S>,
+}
+
+// distances from the start to every reached state, with every predecessor on a shortest path
+#[derive(Debug, Clone)]
+pub struct ShortestPaths<S> {
+    distances: HashMap<S, usize>,
+    predecessors: HashMap<S, Vec<S>>,
+}
+
+impl<S: Clone + Eq + Hash> ShortestPaths<S> {
+    pub fn distance(&self, state: &S) -> Option<usize> {
+        self.distances.get(state).copied()
+    }
+
+    pub fn predecessors(&self, state: &S) -> &[S] {
+        self.predecessors.get(state).map_or(&[], Vec::as_slice)
+    }
+
+    // one of the shortest paths, following the first predecessor of every state
+    pub fn path_to(&self, goal: &S) -> Option<Path<S>> {
+        let distance = self.distance(goal)?;
+        let mut states = vec![goal.clone()];
+        while let Some(previous) = self.predecessors(states.last().unwrap()).first() {
+            states.push(previous.clone());
+        }
+        states.reverse();
+        Some(Path { distance, states })
+    }
+
+    // the closest goals, if any goal was reached
+    pub fn closest(&self, mut is_goal: impl FnMut(&S) -> bool) -> Vec<S> {
+        let goals: Vec<_> = self
+            .distances
+            .iter()
+            .filter(|(state, _)| is_goal(state))
+            .collect();
+        let Some(best) = goals.iter().map(|(_, &d)| d).min() else {
+            return vec![];
+        };
+        goals
+            .into_iter()
+            .filter(|(_, &d)| d == best)
+            .map(|(state, _)| state.clone())
+            .collect()
+    }
+
+    // every state on any shortest path to the closest goals
+    pub fn on_shortest_paths(&self, is_goal: impl FnMut(&S) -> bool) -> HashSet<S> {
+        let mut queue = self.closest(is_goal);
+        let mut seen: HashSet<S> = queue.iter().cloned().collect();
+        while let Some(state) = queue.pop() {
+            for previous in self.predecessors(&state) {
+                if seen.insert(previous.clone()) {
+                    queue.push(previous.clone());
+                }
+            }
+        }
+        seen
+    }
+}
+
+pub fn bfs<S, I>(
+    start: S,
+    mut successors: impl FnMut(&S) -> I,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Option<Path<S>>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut predecessors: HashMap<S, S> = HashMap::new();
+    let mut seen = HashSet::from([start.clone()]);
+    let mut queue = VecDeque::from([start]);
+    while let Some(state) = queue.pop_front() {
+        if is_goal(&state) {
+            let mut states = vec![state];
+            while let Some(previous) = predecessors.get(states.last().unwrap()) {
+                states.push(previous.clone());
+            }
+            states.reverse();
+            return Some(Path {
+                distance: states.len() - 1,
+                states,
+            });
+        }
+        for next in successors(&state) {
+            if seen.insert(next.clone()) {
+                predecessors.insert(next.clone(), state.clone());
+                queue.push_back(next);
+            }
+        }
+    }
+    None
+}
+
+// successors yield the next state with the cost of getting there
+pub fn dijkstra<S, I>(
+    start: S,
+    successors: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Option<Path<S>>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = (S, usize)>,
+{
+    astar(start, successors, |_| 0, is_goal)
+}
+
+// the heuristic must never overestimate the remaining cost, or the path is not the shortest
+pub fn astar<S, I>(
+    start: S,
+    successors: impl FnMut(&S) -> I,
+    heuristic: impl FnMut(&S) -> usize,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Option<Path<S>>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = (S, usize)>,
+{
+    let mut goal = None;
+    let paths = search(start, successors, heuristic, |state| {
+        let found = is_goal(state);
+        if found {
+            goal = Some(state.clone());
+        }
+        found
+    });
+    paths.path_to(&goal?)
+}
+
+// explores everything reachable, keeping all predecessors for "all shortest paths" queries
+pub fn dijkstra_all<S, I>(start: S, successors: impl FnMut(&S) -> I) -> ShortestPaths<S>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = (S, usize)>,
+{
+    search(start, successors, |_| 0, |_| false)
+}
+
+fn search<S, I>(
+    start: S,
+    mut successors: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> usize,
+    mut stop: impl FnMut(&S) -> bool,
+) -> ShortestPaths<S>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = (S, usize)>,
+{
+    let mut paths = ShortestPaths {
+        distances: HashMap::from([(start.clone(), 0)]),
+        predecessors: HashMap::new(),
+    };
+    // the heap holds indices into states, so states need no ordering
+    let mut heap = BinaryHeap::from([Reverse((heuristic(&start), 0, 0))]);
+    let mut states = vec![start];
+    while let Some(Reverse((_, distance, i))) = heap.pop() {
+        let state = states[i].clone();
+        if distance > paths.distances[&state] {
+            continue;
+        }
+        if stop(&state) {
+            break;
+        }
+        for (next, cost) in successors(&state) {
+            // nothing beats the start, and a zero-cost edge back to it would make path_to loop
+            if next == states[0] {
+                continue;
+            }
+            let next_distance = distance + cost;
+            match paths.distances.get(&next) {
+                Some(&d) if d < next_distance => continue,
+                Some(&d) if d == next_distance => {
+                    paths
+                        .predecessors
+                        .entry(next)
+                        .or_default()
+                        .push(state.clone());
+                    continue;
+                }
+                _ => {}
+            }
+            paths.distances.insert(next.clone(), next_distance);
+            paths.predecessors.insert(next.clone(), vec![state.clone()]);
+            heap.push(Reverse((
+                next_distance + heuristic(&next),
+                next_distance,
+                states.len(),
+            )));
+            states.push(next);
+        }
+    }
+    paths
+}
+
+// a position together with the direction it is facing, for costs that depend on turning
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Heading {
+    pub pos: BoundVector2D,
+    pub direction: Direction,
+}
+
+impl Heading {
+    pub fn new(pos: BoundVector2D, direction: Direction) -> Self {
+        Self { pos, direction }
+    }
+
+    // the same position facing the other directions, with the number of quarter turns
+    fn turns(&self) -> [(Heading, usize); 3] {
+        [
+            (Heading::new(self.pos, self.direction.turn_right()), 1),
+            (Heading::new(self.pos, self.direction.turn_left()), 1),
+            (Heading::new(self.pos, self.direction.opposite()), 2),
+        ]
+    }
+}
+
+fn manhattan(a: BoundVector2D, b: BoundVector2D) -> usize {
+    a.x.abs_diff(b.x) + a.y.abs_diff(b.y)
+}
+
+impl<T> Grid<T> {
+    // fewest steps between two cells, moving in the four cardinal directions
+    pub fn bfs(
+        &self,
+        start: BoundVector2D,
+        goal: BoundVector2D,
+        mut passable: impl FnMut(&T) -> bool,
+    ) -> Option<Path<BoundVector2D>> {
+        let goal = self.bound(goal)?;
+        bfs(
+            self.bound(start)?,
+            |&idx| {
+                self.neighbors4(idx)
+                    .filter(|(_, _, value)| passable(value))
+                    .map(|(_, n, _)| n)
+                    .collect::<Vec<_>>()
+            },
+            |&idx| idx == goal,
+        )
+    }
+
+    // cost gives the price of entering a cell, None for walls
+    pub fn dijkstra(
+        &self,
+        start: BoundVector2D,
+        goal: BoundVector2D,
+        cost: impl FnMut(&T) -> Option<usize>,
+    ) -> Option<Path<BoundVector2D>> {
+        self.astar_with(start, goal, cost, |_| 0)
+    }
+
+    // like dijkstra, guided by the manhattan distance, so every cell must cost at least 1
+    pub fn astar(
+        &self,
+        start: BoundVector2D,
+        goal: BoundVector2D,
+        cost: impl FnMut(&T) -> Option<usize>,
+    ) -> Option<Path<BoundVector2D>> {
+        let target = self.bound(goal)?;
+        self.astar_with(start, goal, cost, |&idx| manhattan(idx, target))
+    }
+
+    fn astar_with(
+        &self,
+        start: BoundVector2D,
+        goal: BoundVector2D,
+        mut cost: impl FnMut(&T) -> Option<usize>,
+        heuristic: impl FnMut(&BoundVector2D) -> usize,
+    ) -> Option<Path<BoundVector2D>> {
+        let goal = self.bound(goal)?;
+        astar(
+            self.bound(start)?,
+            |&idx| {
+                self.neighbors4(idx)
+                    .filter_map(|(_, n, value)| Some((n, cost(value)?)))
+                    .collect::<Vec<_>>()
+            },
+            heuristic,
+            |&idx| idx == goal,
+        )
+    }
+
+    // moves of a heading: one step forward onto a passable cell, or turning on the spot
+    pub fn heading_moves(
+        &self,
+        heading: &Heading,
+        mut passable: impl FnMut(&T) -> bool,
+        step_cost: usize,
+        turn_cost: usize,
+    ) -> Vec<(Heading, usize)> {
+        let mut moves: Vec<_> = heading
+            .turns()
+            .into_iter()
+            .map(|(turned, quarter_turns)| (turned, quarter_turns * turn_cost))
+            .collect();
+        let forward = (heading.pos + heading.direction.get_vector())
+            .and_then(|n| self.bound(n))
+            .filter(|&n| passable(&self[n]));
+        if let Some(n) = forward {
+            moves.push((Heading::new(n, heading.direction.clone()), step_cost));
+        }
+        moves
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::char_grid::CharGrid;
+
+    const MAZE: &str = "\
+#######
+#S..#E#
+#.#.#.#
+#.....#
+#######";
+
+    fn find(grid: &CharGrid, c: char) -> BoundVector2D {
+        grid.position(|&v| v == c).unwrap()
+    }
+
+    fn coords(states: &[BoundVector2D]) -> Vec<(usize, usize)> {
+        states.iter().map(|i| (i.x, i.y)).collect()
+    }
+
+    #[test]
+    fn test_bfs() {
+        let result = bfs(0, |&n| [n + 1, n * 2], |&n| n == 10).unwrap();
+        assert_eq!(result.distance, 5);
+        assert_eq!(result.states, vec![0, 1, 2, 4, 5, 10]);
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        // going through 2 is cheaper even though it takes more steps
+        let edges = HashMap::from([
+            (0, vec![(1, 10), (2, 1)]),
+            (1, vec![(3, 1)]),
+            (2, vec![(4, 2)]),
+            (4, vec![(1, 1), (3, 8)]),
+        ]);
+        let result = dijkstra(
+            0,
+            |n| edges.get(n).cloned().unwrap_or_default(),
+            |&n| n == 3,
+        );
+        assert_eq!(
+            result,
+            Some(Path {
+                distance: 5,
+                states: vec![0, 2, 4, 1, 3]
+            })
+        );
+    }
+
+    #[test]
+    fn test_unreachable() {
+        assert_eq!(
+            bfs(1, |&n| (n < 100).then_some(n * 2), |&n| n == 3),
+            None::<Path<u32>>
+        );
+        let result = dijkstra(1u32, |&n| (n < 100).then_some((n * 2, 1)), |&n| n == 3);
+        assert_eq!(result, None);
+    }
+
+    #[test]
+    fn test_grid_bfs() {
+        let grid = CharGrid::from_string(MAZE).unwrap();
+        let path = grid
+            .bfs(find(&grid, 'S'), find(&grid, 'E'), |&c| c != '#')
+            .unwrap();
+        assert_eq!(path.distance, 8);
+        assert_eq!(path.states.len(), 9);
+        assert_eq!(coords(&path.states[..1]), vec![(1, 1)]);
+        assert_eq!(coords(&path.states[8..]), vec![(1, 5)]);
+    }
+
+    #[test]
+    fn test_grid_bfs_blocked() {
+        let grid = CharGrid::from_string("S#E").unwrap();
+        let result = grid.bfs(find(&grid, 'S'), find(&grid, 'E'), |&c| c != '#');
+        assert_eq!(result, None);
+    }
+
+    #[test]
+    fn test_grid_dijkstra_and_astar() {
+        let grid = CharGrid::from_string("1911\n1919\n1119").unwrap();
+        let cost = |c: &char| c.to_digit(10).map(|d| d as usize);
+        let start = BoundVector2D::new(0, 0, 3, 4).unwrap();
+        let goal = BoundVector2D::new(0, 3, 3, 4).unwrap();
+
+        let path = grid.dijkstra(start, goal, cost).unwrap();
+        assert_eq!(path.distance, 7);
+        let path = grid.astar(start, goal, cost).unwrap();
+        assert_eq!(path.distance, 7);
+
+        let cheap = grid.map(|c| if *c == '9' { '#' } else { *c });
+        let path = cheap.astar(start, goal, cost).unwrap();
+        assert_eq!(
+            coords(&path.states),
+            vec![
+                (0, 0),
+                (1, 0),
+                (2, 0),
+                (2, 1),
+                (2, 2),
+                (1, 2),
+                (0, 2),
+                (0, 3)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_heading_turn_costs() {
+        let grid = CharGrid::from_string(MAZE).unwrap();
+        let start = Heading::new(find(&grid, 'S'), Direction::Forward);
+        let goal = find(&grid, 'E');
+        let path = dijkstra(
+            start,
+            |h| grid.heading_moves(h, |&c| c != '#', 1, 1000),
+            |h| h.pos == goal,
+        )
+        .unwrap();
+        // 8 steps and 3 turns, whichever way round the pillar
+        assert_eq!(path.distance, 3008);
+    }
+
+    #[test]
+    fn test_all_shortest_paths() {
+        let grid = CharGrid::from_string(MAZE).unwrap();
+        let start = Heading::new(find(&grid, 'S'), Direction::Forward);
+        let goal = find(&grid, 'E');
+        let paths = dijkstra_all(start, |h| grid.heading_moves(h, |&c| c != '#', 1, 1000));
+
+        let ends = paths.closest(|h| h.pos == goal);
+        assert_eq!(ends.len(), 1);
+        assert_eq!(paths.distance(&ends[0]), Some(3008));
+
+        let cells: HashSet<_> = paths
+            .on_shortest_paths(|h| h.pos == goal)
+            .into_iter()
+            .map(|h| h.pos)
+            .collect();
+        // both ways round the pillar at (2, 2)
+        assert_eq!(cells.len(), 12);
+        assert!(cells.contains(&BoundVector2D::new(1, 3, 5, 7).unwrap()));
+        assert!(cells.contains(&BoundVector2D::new(3, 1, 5, 7).unwrap()));
+
+        let turn = Heading::new(find(&grid, 'S'), Direction::Down);
+        assert_eq!(paths.distance(&turn), Some(1000));
+        assert_eq!(paths.predecessors(&turn).len(), 1);
+    }
+
+    #[test]
+    fn test_free_turns() {
+        let grid = CharGrid::from_string(MAZE).unwrap();
+        let start = Heading::new(find(&grid, 'S'), Direction::Forward);
+        let goal = find(&grid, 'E');
+        let moves = |h: &Heading| grid.heading_moves(h, |&c| c != '#', 1, 0);
+        let path = dijkstra(start.clone(), moves, |h| h.pos == goal).unwrap();
+        assert_eq!(path.distance, 8);
+        assert_eq!(path.states[0], start);
+
+        let paths = dijkstra_all(start.clone(), moves);
+        assert!(paths.predecessors(&start).is_empty());
+        assert_eq!(paths.path_to(&start).unwrap().states, vec![start.clone()]);
+        let turn = Heading::new(start.pos, Direction::Down);
+        assert_eq!(paths.distance(&turn), Some(0));
+        assert_eq!(paths.path_to(&turn).unwrap().states, vec![start, turn]);
+    }
+
+    #[test]
+    fn test_path_to() {
+        let paths = dijkstra_all(0, |&n| {
+            if n < 4 {
+                vec![(n + 1, 1), (n + 2, 2)]
+            } else {
+                vec![]
+            }
+        });
+        assert_eq!(paths.distance(&4), Some(4));
+        assert_eq!(paths.predecessors(&4), &[2, 3]);
+        assert_eq!(paths.path_to(&4).unwrap().states, vec![0, 2, 4]);
+        assert_eq!(paths.path_to(&9), None);
+    }
+}
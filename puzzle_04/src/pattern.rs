@@ -0,0 +1,147 @@
+use crate::{
+    char_grid::CharGrid,
+    grid::{Grid, GridError},
+    vector::BoundVector2D,
+};
+
+// a small template of characters where '.' and '?' match any character
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Pattern {
+    cells: Grid<Option<char>>,
+}
+
+impl Pattern {
+    pub fn from_string(template: &str) -> Result<Self, GridError> {
+        let cells = Grid::from_string_with(template, |c| (c != '.' && c != '?').then_some(c))?;
+        Ok(Self { cells })
+    }
+
+    pub fn rows(&self) -> usize {
+        self.cells.rows
+    }
+
+    pub fn cols(&self) -> usize {
+        self.cells.cols
+    }
+
+    // the whole pattern, wildcards included, on the grid with its top left cell on anchor
+    pub fn matches_at(&self, grid: &CharGrid, anchor: BoundVector2D) -> bool {
+        let Some(anchor) = grid.bound(anchor) else {
+            return false;
+        };
+        if anchor.x + self.rows() > grid.rows || anchor.y + self.cols() > grid.cols {
+            return false;
+        }
+        self.cells.iter().all(|(idx, cell)| match cell {
+            Some(expected) => {
+                (anchor + idx.to_vector()).and_then(|i| grid.get(i)) == Some(expected)
+            }
+            None => true,
+        })
+    }
+
+    // the distinct quarter turns, starting with the pattern itself
+    pub fn rotations(&self) -> Vec<Pattern> {
+        let mut rotations: Vec<Pattern> = vec![];
+        let mut cells = self.cells.clone();
+        for _ in 0..4 {
+            let rotation = Pattern {
+                cells: cells.clone(),
+            };
+            if !rotations.contains(&rotation) {
+                rotations.push(rotation);
+            }
+            cells = cells.rotate_right();
+        }
+        rotations
+    }
+
+    // the distinct rotations of the pattern and of its mirror image
+    pub fn orientations(&self) -> Vec<Pattern> {
+        let mirrored = Pattern {
+            cells: self.cells.flip_cols(),
+        };
+        let mut orientations = self.rotations();
+        for rotation in mirrored.rotations() {
+            if !orientations.contains(&rotation) {
+                orientations.push(rotation);
+            }
+        }
+        orientations
+    }
+}
+
+impl CharGrid {
+    // the top left cell of every place the whole pattern matches
+    pub fn find_pattern(&self, pattern: &Pattern) -> Vec<BoundVector2D> {
+        self.iter_indices()
+            .filter(|&idx| pattern.matches_at(self, idx))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn anchors(found: Vec<BoundVector2D>) -> Vec<(usize, usize)> {
+        found.iter().map(|i| (i.x, i.y)).collect()
+    }
+
+    #[test]
+    fn test_from_string() {
+        let pattern = Pattern::from_string("M.S\n?A?").unwrap();
+        assert_eq!((pattern.rows(), pattern.cols()), (2, 3));
+        assert!(Pattern::from_string("ab\nc").is_err());
+    }
+
+    #[test]
+    fn test_find_pattern() {
+        let grid = CharGrid::from_string("MXSM\nXAXA\nMXSX").unwrap();
+        let pattern = Pattern::from_string("M.S\n.A.\nM.S").unwrap();
+        assert_eq!(anchors(grid.find_pattern(&pattern)), vec![(0, 0)]);
+    }
+
+    #[test]
+    fn test_find_pattern_wildcards_and_overlaps() {
+        let grid = CharGrid::from_string("aaa\naaa").unwrap();
+        let pattern = Pattern::from_string("a?").unwrap();
+        assert_eq!(
+            anchors(grid.find_pattern(&pattern)),
+            vec![(0, 0), (0, 1), (1, 0), (1, 1)]
+        );
+        let too_big = Pattern::from_string("aaaa").unwrap();
+        assert!(grid.find_pattern(&too_big).is_empty());
+    }
+
+    #[test]
+    fn test_matches_at_edge() {
+        let grid = CharGrid::from_string("ab\ncd").unwrap();
+        let pattern = Pattern::from_string("d?").unwrap();
+        let anchor = BoundVector2D::new(1, 1, 2, 2).unwrap();
+        assert!(!pattern.matches_at(&grid, anchor));
+        let pattern = Pattern::from_string("d.\n..").unwrap();
+        assert!(!pattern.matches_at(&grid, anchor));
+        let pattern = Pattern::from_string("d").unwrap();
+        assert!(pattern.matches_at(&grid, anchor));
+    }
+
+    #[test]
+    fn test_rotations() {
+        let pattern = Pattern::from_string("M.S\n.A.\nM.S").unwrap();
+        let rotations = pattern.rotations();
+        assert_eq!(rotations.len(), 4);
+        assert_eq!(rotations[1], Pattern::from_string("M.M\n.A.\nS.S").unwrap());
+        let symmetric = Pattern::from_string("X.X\n.X.\nX.X").unwrap();
+        assert_eq!(symmetric.rotations().len(), 1);
+    }
+
+    #[test]
+    fn test_orientations() {
+        let l_shape = Pattern::from_string("#.\n#.\n##").unwrap();
+        assert_eq!(l_shape.rotations().len(), 4);
+        assert_eq!(l_shape.orientations().len(), 8);
+        let line = Pattern::from_string("ab").unwrap();
+        assert_eq!(line.orientations().len(), 4);
+    }
+}
@@ -0,0 +1,152 @@
+use std::collections::VecDeque;
+
+use crate::{grid::Grid, vector::BoundVector2D};
+
+// the connected components of a grid, every cell is labeled with the index of its region
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Regions {
+    pub labels: Grid<usize>,
+    pub cells: Vec<Vec<BoundVector2D>>,
+}
+
+impl Regions {
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn label(&self, idx: BoundVector2D) -> Option<usize> {
+        self.labels.get(idx).copied()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &[BoundVector2D]> + '_ {
+        self.cells.iter().map(Vec::as_slice)
+    }
+}
+
+impl<T> Grid<T> {
+    // 4-connected cells reachable from start, where same decides if two neighbors belong together
+    pub fn flood_fill(
+        &self,
+        start: BoundVector2D,
+        mut same: impl FnMut(&T, &T) -> bool,
+    ) -> Vec<BoundVector2D> {
+        let mut seen = Grid::new(self.rows, self.cols, false);
+        self.fill_from(start, &mut seen, &mut same)
+    }
+
+    pub fn regions(&self, mut same: impl FnMut(&T, &T) -> bool) -> Regions {
+        let mut seen = Grid::new(self.rows, self.cols, false);
+        let mut labels = Grid::new(self.rows, self.cols, 0);
+        let mut cells = vec![];
+        for idx in self.iter_indices() {
+            if seen[idx] {
+                continue;
+            }
+            let region = self.fill_from(idx, &mut seen, &mut same);
+            for &cell in &region {
+                labels[cell] = cells.len();
+            }
+            cells.push(region);
+        }
+        Regions { labels, cells }
+    }
+
+    fn fill_from(
+        &self,
+        start: BoundVector2D,
+        seen: &mut Grid<bool>,
+        same: &mut impl FnMut(&T, &T) -> bool,
+    ) -> Vec<BoundVector2D> {
+        let Some(start) = self.bound(start) else {
+            return vec![];
+        };
+        let mut region = vec![];
+        let mut queue = VecDeque::from([start]);
+        seen[start] = true;
+        while let Some(idx) = queue.pop_front() {
+            region.push(idx);
+            for (_, n, value) in self.neighbors4(idx) {
+                if !seen[n] && same(&self[idx], value) {
+                    seen[n] = true;
+                    queue.push_back(n);
+                }
+            }
+        }
+        region
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::char_grid::CharGrid;
+
+    fn idx(x: usize, y: usize) -> BoundVector2D {
+        BoundVector2D::new(x, y, 4, 4).unwrap()
+    }
+
+    fn sorted(cells: &[BoundVector2D]) -> Vec<(usize, usize)> {
+        let mut cells: Vec<_> = cells.iter().map(|i| (i.x, i.y)).collect();
+        cells.sort();
+        cells
+    }
+
+    #[test]
+    fn test_flood_fill() {
+        let grid = CharGrid::from_string("AAAA\nBBCD\nBBCC\nEEEC").unwrap();
+        let region = grid.flood_fill(idx(1, 2), |a, b| a == b);
+        assert_eq!(sorted(&region), vec![(1, 2), (2, 2), (2, 3), (3, 3)]);
+    }
+
+    #[test]
+    fn test_flood_fill_is_not_diagonal() {
+        let grid = CharGrid::from_string("X.\n.X").unwrap();
+        assert_eq!(grid.flood_fill(idx(0, 0), |a, b| a == b).len(), 1);
+    }
+
+    #[test]
+    fn test_flood_fill_out_of_bounds() {
+        let grid = CharGrid::from_string("ab\ncd").unwrap();
+        assert!(grid.flood_fill(idx(3, 3), |a, b| a == b).is_empty());
+    }
+
+    #[test]
+    fn test_regions() {
+        let grid = CharGrid::from_string("AAAA\nBBCD\nBBCC\nEEEC").unwrap();
+        let regions = grid.regions(|a, b| a == b);
+        assert_eq!(regions.len(), 5);
+        let sizes: Vec<_> = regions.iter().map(|r| r.len()).collect();
+        assert_eq!(sizes, vec![4, 4, 4, 1, 3]);
+        assert_eq!(regions.label(idx(0, 3)), Some(0));
+        assert_eq!(regions.label(idx(3, 3)), regions.label(idx(1, 2)));
+        assert_ne!(regions.label(idx(1, 3)), regions.label(idx(1, 2)));
+    }
+
+    #[test]
+    fn test_regions_same_letter_apart() {
+        let grid = CharGrid::from_string("XOX\nXOX").unwrap();
+        let regions = grid.regions(|a, b| a == b);
+        assert_eq!(regions.len(), 3);
+        assert_ne!(regions.label(idx(0, 0)), regions.label(idx(0, 2)));
+        assert_eq!(sorted(&regions.cells[2]), vec![(0, 2), (1, 2)]);
+    }
+
+    #[test]
+    fn test_regions_custom_predicate() {
+        let grid = CharGrid::from_string("1234\n9876").unwrap();
+        let regions = grid.regions(|a, b| a.is_ascii_digit() && b.is_ascii_digit());
+        assert_eq!(regions.len(), 1);
+
+        let heights = grid.map(|c| c.to_digit(10).unwrap());
+        let slopes = heights.regions(|a, b| a.abs_diff(*b) == 1);
+        assert_eq!(
+            slopes.iter().map(|r| r.len()).collect::<Vec<_>>(),
+            vec![4, 4]
+        );
+    }
+}
@@ -0,0 +1,257 @@
+use std::{
+    collections::HashSet,
+    fmt::{self, Display},
+};
+
+use crate::{char_grid::CharGrid, vector::BoundVector2D};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Color {
+    Black,
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+    White,
+}
+
+impl Color {
+    fn code(&self) -> u8 {
+        match self {
+            Self::Black => 30,
+            Self::Red => 31,
+            Self::Green => 32,
+            Self::Yellow => 33,
+            Self::Blue => 34,
+            Self::Magenta => 35,
+            Self::Cyan => 36,
+            Self::White => 37,
+        }
+    }
+}
+
+// cells drawn with a replacement character, a color or both
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Overlay {
+    cells: HashSet<(usize, usize)>,
+    pub replacement: Option<char>,
+    pub color: Option<Color>,
+}
+
+impl Overlay {
+    pub fn new(cells: impl IntoIterator<Item = BoundVector2D>) -> Self {
+        Self {
+            cells: cells.into_iter().map(|idx| (idx.x, idx.y)).collect(),
+            replacement: None,
+            color: None,
+        }
+    }
+
+    pub fn with_char(mut self, replacement: char) -> Self {
+        self.replacement = Some(replacement);
+        self
+    }
+
+    pub fn with_color(mut self, color: Color) -> Self {
+        self.color = Some(color);
+        self
+    }
+
+    fn contains(&self, x: usize, y: usize) -> bool {
+        self.cells.contains(&(x, y))
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Renderer<'a> {
+    grid: &'a CharGrid,
+    overlays: Vec<Overlay>,
+    rulers: bool,
+    color: bool,
+}
+
+impl<'a> Renderer<'a> {
+    pub fn new(grid: &'a CharGrid) -> Self {
+        Self {
+            grid,
+            overlays: vec![],
+            rulers: false,
+            color: true,
+        }
+    }
+
+    // later overlays are drawn on top of earlier ones
+    pub fn overlay(mut self, overlay: Overlay) -> Self {
+        self.overlays.push(overlay);
+        self
+    }
+
+    // row numbers to the left and column numbers above, one line per digit
+    pub fn rulers(mut self) -> Self {
+        self.rulers = true;
+        self
+    }
+
+    // without the ANSI escape codes, for comparing or writing to files
+    pub fn plain(mut self) -> Self {
+        self.color = false;
+        self
+    }
+
+    fn cell(&self, x: usize, y: usize, c: char) -> (char, Option<Color>) {
+        let mut on_top = self.overlays.iter().rev().filter(|o| o.contains(x, y));
+        let replacement = on_top.clone().find_map(|o| o.replacement);
+        let color = on_top.find_map(|o| o.color);
+        (replacement.unwrap_or(c), color)
+    }
+
+    fn write_column_rulers(&self, f: &mut fmt::Formatter<'_>, margin: usize) -> fmt::Result {
+        let places = digits(self.grid.cols.saturating_sub(1));
+        for place in (0..places).rev() {
+            let unit = 10usize.pow(place as u32);
+            write!(f, "{:margin$}", "")?;
+            for y in 0..self.grid.cols {
+                if place > 0 && y < unit {
+                    write!(f, " ")?;
+                } else {
+                    write!(f, "{}", y / unit % 10)?;
+                }
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+fn digits(n: usize) -> usize {
+    n.to_string().len()
+}
+
+impl CharGrid {
+    pub fn render(&self) -> Renderer<'_> {
+        Renderer::new(self)
+    }
+}
+
+impl Display for CharGrid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (x, row) in self.cells().chunks(self.cols.max(1)).enumerate() {
+            if x > 0 {
+                writeln!(f)?;
+            }
+            write!(f, "{}", row.iter().collect::<String>())?;
+        }
+        Ok(())
+    }
+}
+
+impl Display for Renderer<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let width = digits(self.grid.rows.saturating_sub(1));
+        if self.rulers {
+            self.write_column_rulers(f, width + 1)?;
+        }
+        for (x, row) in self.grid.cells().chunks(self.grid.cols.max(1)).enumerate() {
+            if x > 0 {
+                writeln!(f)?;
+            }
+            if self.rulers {
+                write!(f, "{:>width$} ", x)?;
+            }
+            for (y, &c) in row.iter().enumerate() {
+                match self.cell(x, y, c) {
+                    (c, Some(color)) if self.color => {
+                        write!(f, "\x1b[{}m{}\x1b[0m", color.code(), c)?
+                    }
+                    (c, _) => write!(f, "{}", c)?,
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn idx(x: usize, y: usize) -> BoundVector2D {
+        BoundVector2D::new(x, y, 2, 3).unwrap()
+    }
+
+    #[test]
+    fn test_display() {
+        let grid = CharGrid::from_string("abc\ndef\n").unwrap();
+        assert_eq!(grid.to_string(), "abc\ndef");
+        assert_eq!(CharGrid::from_string("").unwrap().to_string(), "");
+    }
+
+    #[test]
+    fn test_render_without_overlays() {
+        let grid = CharGrid::from_string("abc\ndef").unwrap();
+        assert_eq!(grid.render().to_string(), grid.to_string());
+    }
+
+    #[test]
+    fn test_overlay_replacement() {
+        let grid = CharGrid::from_string("abc\ndef").unwrap();
+        let rendered = grid
+            .render()
+            .overlay(Overlay::new([idx(0, 1), idx(1, 2)]).with_char('#'))
+            .to_string();
+        assert_eq!(rendered, "a#c\nde#");
+    }
+
+    #[test]
+    fn test_overlay_color() {
+        let grid = CharGrid::from_string("ab").unwrap();
+        let overlay = Overlay::new([idx(0, 1)]).with_color(Color::Red);
+        let rendered = grid.render().overlay(overlay.clone()).to_string();
+        assert_eq!(rendered, "a\x1b[31mb\x1b[0m");
+        let rendered = grid.render().overlay(overlay).plain().to_string();
+        assert_eq!(rendered, "ab");
+    }
+
+    #[test]
+    fn test_later_overlays_on_top() {
+        let grid = CharGrid::from_string("abc").unwrap();
+        let rendered = grid
+            .render()
+            .overlay(
+                Overlay::new([idx(0, 0), idx(0, 1)])
+                    .with_char('X')
+                    .with_color(Color::Green),
+            )
+            .overlay(Overlay::new([idx(0, 1)]).with_char('O'))
+            .overlay(Overlay::new([idx(0, 0)]).with_color(Color::Blue))
+            .to_string();
+        assert_eq!(rendered, "\x1b[34mX\x1b[0m\x1b[32mO\x1b[0mc");
+    }
+
+    #[test]
+    fn test_overlay_ignores_bounds() {
+        let grid = CharGrid::from_string("ab\ncd").unwrap();
+        let cell = BoundVector2D::new(1, 0, 10, 10).unwrap();
+        let rendered = grid.render().overlay(Overlay::new([cell]).with_char('*'));
+        assert_eq!(rendered.to_string(), "ab\n*d");
+    }
+
+    #[test]
+    fn test_rulers() {
+        let grid = CharGrid::from_string("abc\ndef").unwrap();
+        assert_eq!(grid.render().rulers().to_string(), "  012\n0 abc\n1 def");
+    }
+
+    #[test]
+    fn test_rulers_with_two_digits() {
+        let grid = CharGrid::from_string(&vec![".".repeat(12); 11].join("\n")).unwrap();
+        let rendered = grid.render().rulers().to_string();
+        let lines: Vec<_> = rendered.lines().collect();
+        assert_eq!(lines[0], "             11");
+        assert_eq!(lines[1], "   012345678901");
+        assert_eq!(lines[2], " 0 ............");
+        assert_eq!(lines[12], "10 ............");
+    }
+}
@@ -0,0 +1,200 @@
+use std::{collections::HashSet, hash::Hash};
+
+use crate::{direction::Direction, grid::Grid, vector::BoundVector2D};
+
+// where an agent stands and faces, extra holds whatever else the rule keeps track of
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Agent<E = ()> {
+    pub pos: BoundVector2D,
+    pub direction: Direction,
+    pub extra: E,
+}
+
+impl Agent {
+    pub fn new(pos: BoundVector2D, direction: Direction) -> Self {
+        Self::with(pos, direction, ())
+    }
+}
+
+impl<E: Clone> Agent<E> {
+    pub fn with(pos: BoundVector2D, direction: Direction, extra: E) -> Self {
+        Self {
+            pos,
+            direction,
+            extra,
+        }
+    }
+
+    // the cell one step in the facing direction, None at the edge
+    pub fn ahead(&self) -> Option<BoundVector2D> {
+        self.pos + self.direction.get_vector()
+    }
+
+    pub fn moved_to(&self, pos: BoundVector2D) -> Self {
+        Self {
+            pos,
+            ..self.clone()
+        }
+    }
+
+    pub fn facing(&self, direction: Direction) -> Self {
+        Self {
+            direction,
+            ..self.clone()
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum End {
+    // the rule returned no state or one off the grid
+    LeftGrid,
+    // the next state was seen before, so the agent would go round forever
+    Loop,
+}
+
+// an agent advanced by rule one state at a time, iterating yields every state
+// from the start until the agent leaves the grid or repeats itself
+pub struct Simulation<'a, T, E, R> {
+    grid: &'a Grid<T>,
+    rule: R,
+    next: Option<Agent<E>>,
+    seen: HashSet<Agent<E>>,
+    end: Option<End>,
+}
+
+impl<'a, T, E, R> Simulation<'a, T, E, R>
+where
+    E: Clone + Eq + Hash,
+    R: FnMut(&Grid<T>, &Agent<E>) -> Option<Agent<E>>,
+{
+    pub fn new(grid: &'a Grid<T>, start: Agent<E>, rule: R) -> Self {
+        let on_grid = grid.get(start.pos).is_some();
+        Self {
+            grid,
+            rule,
+            next: on_grid.then_some(start),
+            seen: HashSet::new(),
+            end: (!on_grid).then_some(End::LeftGrid),
+        }
+    }
+
+    // None while the simulation is still running
+    pub fn end(&self) -> Option<End> {
+        self.end
+    }
+
+    pub fn run(mut self) -> End {
+        self.by_ref().for_each(drop);
+        self.end.unwrap()
+    }
+}
+
+impl<T, E, R> Iterator for Simulation<'_, T, E, R>
+where
+    E: Clone + Eq + Hash,
+    R: FnMut(&Grid<T>, &Agent<E>) -> Option<Agent<E>>,
+{
+    type Item = Agent<E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let current = self.next.take()?;
+        if !self.seen.insert(current.clone()) {
+            self.end = Some(End::Loop);
+            return None;
+        }
+        match (self.rule)(self.grid, &current) {
+            Some(next) if self.grid.get(next.pos).is_some() => self.next = Some(next),
+            _ => self.end = Some(End::LeftGrid),
+        }
+        Some(current)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::char_grid::CharGrid;
+
+    fn idx(x: usize, y: usize) -> BoundVector2D {
+        BoundVector2D::new(x, y, 3, 3).unwrap()
+    }
+
+    // walks straight and turns right in front of '#'
+    fn guard(grid: &CharGrid, guard: &Agent) -> Option<Agent> {
+        let next = guard.ahead()?;
+        Some(if grid[next] == '#' {
+            guard.facing(guard.direction.turn_right())
+        } else {
+            guard.moved_to(next)
+        })
+    }
+
+    #[test]
+    fn test_leaves_grid() {
+        let grid = CharGrid::from_string("...\n...\n...").unwrap();
+        let mut simulation = Simulation::new(&grid, Agent::new(idx(2, 1), Direction::Up), guard);
+        let path: Vec<_> = simulation.by_ref().map(|a| (a.pos.x, a.pos.y)).collect();
+        assert_eq!(path, vec![(2, 1), (1, 1), (0, 1)]);
+        assert_eq!(simulation.end(), Some(End::LeftGrid));
+    }
+
+    #[test]
+    fn test_turns_yield_a_step() {
+        let grid = CharGrid::from_string(".#.\n...\n...").unwrap();
+        let states: Vec<_> = Simulation::new(&grid, Agent::new(idx(1, 1), Direction::Up), guard)
+            .map(|a| (a.pos.x, a.pos.y, a.direction))
+            .collect();
+        assert_eq!(
+            states,
+            vec![
+                (1, 1, Direction::Up),
+                (1, 1, Direction::Forward),
+                (1, 2, Direction::Forward)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_loop() {
+        let grid = CharGrid::from_string(".#..\n...#\n#...\n..#.").unwrap();
+        let start = BoundVector2D::new(1, 1, 4, 4).unwrap();
+        let mut simulation = Simulation::new(&grid, Agent::new(start, Direction::Up), guard);
+        assert_eq!(simulation.end(), None);
+        assert_eq!(simulation.by_ref().count(), 8);
+        assert_eq!(simulation.end(), Some(End::Loop));
+    }
+
+    #[test]
+    fn test_start_off_grid() {
+        let grid = CharGrid::from_string("..\n..").unwrap();
+        let simulation = Simulation::new(&grid, Agent::new(idx(2, 2), Direction::Up), guard);
+        assert_eq!(simulation.run(), End::LeftGrid);
+    }
+
+    #[test]
+    fn test_extra_state() {
+        // counts its steps and stops after three of them
+        let grid = CharGrid::from_string(".....").unwrap();
+        let start = Agent::with(
+            BoundVector2D::new(0, 0, 1, 5).unwrap(),
+            Direction::Forward,
+            0,
+        );
+        let rule = |_: &CharGrid, agent: &Agent<u32>| {
+            if agent.extra == 3 {
+                return None;
+            }
+            let next = agent.moved_to(agent.ahead()?);
+            Some(Agent {
+                extra: agent.extra + 1,
+                ..next
+            })
+        };
+        let steps: Vec<_> = Simulation::new(&grid, start, rule)
+            .map(|a| (a.pos.y, a.extra))
+            .collect();
+        assert_eq!(steps, vec![(0, 0), (1, 1), (2, 2), (3, 3)]);
+    }
+}
@@ -0,0 +1,182 @@
+use std::{collections::HashMap, ops::Index};
+
+use crate::{grid::Grid, vector::Vector2D};
+
+// cells keyed by signed coordinates that may grow in any direction,
+// every cell that was never set reads as the default
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SparseGrid<T> {
+    cells: HashMap<Vector2D, T>,
+    default: T,
+}
+
+impl<T> SparseGrid<T> {
+    pub fn new(default: T) -> Self {
+        Self {
+            cells: HashMap::new(),
+            default,
+        }
+    }
+
+    pub fn get(&self, pos: Vector2D) -> &T {
+        self.cells.get(&pos).unwrap_or(&self.default)
+    }
+
+    // the previous value if the cell was occupied
+    pub fn set(&mut self, pos: Vector2D, value: T) -> Option<T> {
+        self.cells.insert(pos, value)
+    }
+
+    pub fn remove(&mut self, pos: Vector2D) -> Option<T> {
+        self.cells.remove(&pos)
+    }
+
+    pub fn contains(&self, pos: Vector2D) -> bool {
+        self.cells.contains_key(&pos)
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    // the occupied cells in no particular order
+    pub fn iter(&self) -> impl Iterator<Item = (Vector2D, &T)> + '_ {
+        self.cells.iter().map(|(&pos, value)| (pos, value))
+    }
+
+    // the smallest and the largest coordinates of the occupied cells, None while empty
+    pub fn bounds(&self) -> Option<(Vector2D, Vector2D)> {
+        let mut positions = self.cells.keys();
+        let first = *positions.next()?;
+        Some(positions.fold((first, first), |(min, max), pos| {
+            (
+                Vector2D::new(min.x.min(pos.x), min.y.min(pos.y)),
+                Vector2D::new(max.x.max(pos.x), max.y.max(pos.y)),
+            )
+        }))
+    }
+
+    // the cells that differ from the default, with the top left cell of the grid at (0, 0)
+    pub fn from_grid(grid: &Grid<T>, default: T) -> Self
+    where
+        T: Clone + PartialEq,
+    {
+        let mut sparse = Self::new(default);
+        for (idx, value) in grid.iter() {
+            if *value != sparse.default {
+                sparse.set(idx.to_vector(), value.clone());
+            }
+        }
+        sparse
+    }
+
+    // the bounding box as a dense grid, its top left corner ends up at (0, 0)
+    pub fn to_grid(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        let Some((min, max)) = self.bounds() else {
+            return Grid::from_fn(0, 0, |_| self.default.clone());
+        };
+        let size = max - min;
+        Grid::from_fn(size.x as usize + 1, size.y as usize + 1, |idx| {
+            self.get(min + idx.to_vector()).clone()
+        })
+    }
+}
+
+impl<T: Default> Default for SparseGrid<T> {
+    fn default() -> Self {
+        Self::new(T::default())
+    }
+}
+
+impl<T: Default> FromIterator<(Vector2D, T)> for SparseGrid<T> {
+    fn from_iter<I: IntoIterator<Item = (Vector2D, T)>>(iter: I) -> Self {
+        let mut sparse = Self::default();
+        for (pos, value) in iter {
+            sparse.set(pos, value);
+        }
+        sparse
+    }
+}
+
+impl<T> Index<Vector2D> for SparseGrid<T> {
+    type Output = T;
+
+    fn index(&self, index: Vector2D) -> &Self::Output {
+        self.get(index)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::char_grid::CharGrid;
+
+    fn v(x: isize, y: isize) -> Vector2D {
+        Vector2D::new(x, y)
+    }
+
+    #[test]
+    fn test_get_and_set() {
+        let mut sparse = SparseGrid::new('.');
+        assert_eq!(sparse.get(v(-5, 3)), &'.');
+        assert_eq!(sparse.set(v(-5, 3), '#'), None);
+        assert_eq!(sparse.set(v(-5, 3), '@'), Some('#'));
+        assert_eq!(sparse[v(-5, 3)], '@');
+        assert!(sparse.contains(v(-5, 3)));
+        assert_eq!(sparse.len(), 1);
+        assert_eq!(sparse.remove(v(-5, 3)), Some('@'));
+        assert!(sparse.is_empty());
+    }
+
+    #[test]
+    fn test_bounds() {
+        let mut sparse = SparseGrid::new(0);
+        assert_eq!(sparse.bounds(), None);
+        sparse.set(v(2, -1), 1);
+        assert_eq!(sparse.bounds(), Some((v(2, -1), v(2, -1))));
+        sparse.set(v(-3, 4), 1);
+        sparse.set(v(0, 7), 1);
+        assert_eq!(sparse.bounds(), Some((v(-3, -1), v(2, 7))));
+    }
+
+    #[test]
+    fn test_iter() {
+        let sparse: SparseGrid<u8> = [(v(1, 1), 3), (v(-1, 0), 4)].into_iter().collect();
+        let mut cells: Vec<_> = sparse.iter().map(|(pos, &value)| (pos, value)).collect();
+        cells.sort();
+        assert_eq!(cells, vec![(v(-1, 0), 4), (v(1, 1), 3)]);
+        assert_eq!(sparse[v(5, 5)], 0);
+    }
+
+    #[test]
+    fn test_from_grid() {
+        let grid = CharGrid::from_string("#..\n..#").unwrap();
+        let sparse = SparseGrid::from_grid(&grid, '.');
+        assert_eq!(sparse.len(), 2);
+        assert_eq!(sparse[v(1, 2)], '#');
+        assert_eq!(sparse[v(1, 1)], '.');
+    }
+
+    #[test]
+    fn test_to_grid() {
+        let mut sparse = SparseGrid::new('.');
+        sparse.set(v(-1, -2), 'a');
+        sparse.set(v(1, 0), 'b');
+        assert_eq!(sparse.to_grid().to_string(), "a..\n...\n..b");
+        assert_eq!(SparseGrid::new('.').to_grid().rows, 0);
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let grid = CharGrid::from_string("#.#\n.#.\n#.#").unwrap();
+        assert_eq!(SparseGrid::from_grid(&grid, '.').to_grid(), grid);
+    }
+}
@@ -1,8 +1,112 @@
 use std::{
     cmp::min,
-    ops::{Add, Sub},
+    ops::{Add, Mul, Neg, Sub},
 };
 
+// a signed offset or position that may lie off the grid, x is the row and y the column
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy, Hash, PartialOrd, Ord)]
+pub struct Vector2D {
+    pub x: isize,
+    pub y: isize,
+}
+
+impl Vector2D {
+    pub const fn new(x: isize, y: isize) -> Self {
+        Self { x, y }
+    }
+
+    pub fn manhattan(&self) -> usize {
+        self.x.unsigned_abs() + self.y.unsigned_abs()
+    }
+
+    pub fn chebyshev(&self) -> usize {
+        self.x.unsigned_abs().max(self.y.unsigned_abs())
+    }
+
+    // the smallest step in the same direction, the zero vector stays zero
+    pub fn reduced(&self) -> Self {
+        match gcd(self.x.unsigned_abs(), self.y.unsigned_abs()) {
+            0 => *self,
+            d => Self::new(self.x / d as isize, self.y / d as isize),
+        }
+    }
+
+    pub fn bound(&self, bound_x: usize, bound_y: usize) -> Option<BoundVector2D> {
+        BoundVector2D::from_sized(self.x, self.y, bound_x, bound_y)
+    }
+
+    // the same position on a torus of the given size, None if it has no cells
+    pub fn wrap(&self, bound_x: usize, bound_y: usize) -> Option<BoundVector2D> {
+        if bound_x == 0 || bound_y == 0 {
+            return None;
+        }
+        BoundVector2D::from_sized(
+            self.x.rem_euclid(bound_x as isize),
+            self.y.rem_euclid(bound_y as isize),
+            bound_x,
+            bound_y,
+        )
+    }
+}
+
+fn gcd(a: usize, b: usize) -> usize {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+impl From<(isize, isize)> for Vector2D {
+    fn from((x, y): (isize, isize)) -> Self {
+        Self::new(x, y)
+    }
+}
+
+impl From<BoundVector2D> for Vector2D {
+    fn from(v: BoundVector2D) -> Self {
+        v.to_vector()
+    }
+}
+
+impl From<Vector2D> for (isize, isize) {
+    fn from(vector: Vector2D) -> Self {
+        (vector.x, vector.y)
+    }
+}
+
+impl Add for Vector2D {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl Sub for Vector2D {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Self::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl Mul<isize> for Vector2D {
+    type Output = Self;
+
+    fn mul(self, rhs: isize) -> Self {
+        Self::new(self.x * rhs, self.y * rhs)
+    }
+}
+
+impl Neg for Vector2D {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new(-self.x, -self.y)
+    }
+}
+
 #[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
 pub struct BoundVector2D {
     pub x: usize,
@@ -34,6 +138,18 @@ impl BoundVector2D {
     pub fn bound(&self, bound_x: usize, bound_y: usize) -> Option<Self> {
         BoundVector2D::new(self.x, self.y, bound_x, bound_y)
     }
+
+    // grid sizes never exceed isize::MAX, so the cast can't wrap
+    pub fn to_vector(self) -> Vector2D {
+        Vector2D::new(self.x as isize, self.y as isize)
+    }
+
+    // stepping off one edge re-enters on the opposite side
+    pub fn wrapping_add(self, step: Vector2D) -> Self {
+        (self.to_vector() + step)
+            .wrap(self.bound_x, self.bound_y)
+            .unwrap()
+    }
 }
 
 impl Add for BoundVector2D {
@@ -88,6 +204,22 @@ impl Sub<(isize, isize)> for BoundVector2D {
     }
 }
 
+impl Add<Vector2D> for BoundVector2D {
+    type Output = Option<Self>;
+
+    fn add(self, other: Vector2D) -> Option<Self> {
+        self + (other.x, other.y)
+    }
+}
+
+impl Sub<Vector2D> for BoundVector2D {
+    type Output = Option<Self>;
+
+    fn sub(self, other: Vector2D) -> Option<Self> {
+        self - (other.x, other.y)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -319,4 +451,78 @@ mod tests {
         let result = vec.to_sized();
         assert_eq!(result, Some((1, 1)));
     }
+
+    #[test]
+    fn test_vector_arithmetic() {
+        let a = Vector2D::new(3, -2);
+        let b = Vector2D::new(-1, 4);
+        assert_eq!(a + b, Vector2D::new(2, 2));
+        assert_eq!(a - b, Vector2D::new(4, -6));
+        assert_eq!(a * 3, Vector2D::new(9, -6));
+        assert_eq!(-a, Vector2D::new(-3, 2));
+        assert_eq!(a + b - b, a);
+    }
+
+    #[test]
+    fn test_vector_norms() {
+        let vec = Vector2D::new(-3, 5);
+        assert_eq!(vec.manhattan(), 8);
+        assert_eq!(vec.chebyshev(), 5);
+        assert_eq!(Vector2D::default().manhattan(), 0);
+    }
+
+    #[test]
+    fn test_vector_reduced() {
+        assert_eq!(Vector2D::new(4, -6).reduced(), Vector2D::new(2, -3));
+        assert_eq!(Vector2D::new(0, -5).reduced(), Vector2D::new(0, -1));
+        assert_eq!(Vector2D::new(3, 5).reduced(), Vector2D::new(3, 5));
+        assert_eq!(Vector2D::default().reduced(), Vector2D::default());
+    }
+
+    #[test]
+    fn test_vector_bound() {
+        let result = Vector2D::new(2, 3).bound(5, 5);
+        assert_eq!(result, BoundVector2D::new(2, 3, 5, 5));
+        assert_eq!(Vector2D::new(-1, 3).bound(5, 5), None);
+        assert_eq!(Vector2D::new(2, 5).bound(5, 5), None);
+    }
+
+    #[test]
+    fn test_to_vector() {
+        let vec = BoundVector2D::new(4, 1, 5, 5).unwrap();
+        assert_eq!(vec.to_vector(), Vector2D::new(4, 1));
+        assert_eq!(vec.to_vector().bound(5, 5), Some(vec));
+    }
+
+    #[test]
+    fn test_add_vector2d() {
+        let vec = BoundVector2D::new(1, 1, 5, 5).unwrap();
+        assert_eq!(vec + Vector2D::new(2, 3), vec + (2, 3));
+        assert_eq!(vec - Vector2D::new(2, 0), None);
+    }
+
+    #[test]
+    fn test_vector_tuple_conversion() {
+        let vec: Vector2D = (7, -8).into();
+        assert_eq!(vec, Vector2D::new(7, -8));
+        assert_eq!(<(isize, isize)>::from(vec), (7, -8));
+    }
+
+    #[test]
+    fn test_vector_wrap() {
+        let result = Vector2D::new(-1, 7).wrap(5, 5).unwrap();
+        assert_eq!((result.x, result.y), (4, 2));
+        let result = Vector2D::new(-11, -5).wrap(5, 5).unwrap();
+        assert_eq!((result.x, result.y), (4, 0));
+        assert_eq!(Vector2D::new(1, 1).wrap(0, 5), None);
+    }
+
+    #[test]
+    fn test_wrapping_add() {
+        let vec = BoundVector2D::new(0, 4, 3, 5).unwrap();
+        let result = vec.wrapping_add(Vector2D::new(-1, 1));
+        assert_eq!(result, BoundVector2D::new(2, 0, 3, 5).unwrap());
+        let result = vec.wrapping_add(Vector2D::new(3, -15));
+        assert_eq!(result, BoundVector2D::new(0, 4, 3, 5).unwrap());
+    }
 }
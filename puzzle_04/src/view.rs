@@ -0,0 +1,393 @@
+use std::{iter, ops::Index};
+
+use crate::{
+    grid::Grid,
+    vector::{BoundVector2D, Vector2D},
+};
+
+// a transformed window onto a grid without copying it, view indices are
+// mapped to grid indices as origin + x * row_step + y * col_step
+#[derive(Debug)]
+pub struct View<'a, T> {
+    grid: &'a Grid<T>,
+    pub rows: usize,
+    pub cols: usize,
+    origin: Vector2D,
+    row_step: Vector2D,
+    col_step: Vector2D,
+}
+
+impl<T> Clone for View<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for View<'_, T> {}
+
+impl<'a, T> View<'a, T> {
+    pub fn new(grid: &'a Grid<T>) -> Self {
+        Self {
+            grid,
+            rows: grid.rows,
+            cols: grid.cols,
+            origin: Vector2D::new(0, 0),
+            row_step: Vector2D::new(1, 0),
+            col_step: Vector2D::new(0, 1),
+        }
+    }
+
+    // the index in the underlying grid
+    pub fn source(&self, idx: BoundVector2D) -> Option<BoundVector2D> {
+        let idx = idx.bound(self.rows, self.cols)?;
+        let v = self.origin + self.row_step * idx.x as isize + self.col_step * idx.y as isize;
+        self.grid.bound(v)
+    }
+
+    pub fn get(&self, idx: BoundVector2D) -> Option<&'a T> {
+        self.grid.get(self.source(idx)?)
+    }
+
+    fn last_row(&self) -> isize {
+        self.rows as isize - 1
+    }
+
+    fn last_col(&self) -> isize {
+        self.cols as isize - 1
+    }
+
+    pub fn transpose(self) -> Self {
+        Self {
+            rows: self.cols,
+            cols: self.rows,
+            row_step: self.col_step,
+            col_step: self.row_step,
+            ..self
+        }
+    }
+
+    // a quarter turn clockwise, the first column becomes the first row
+    pub fn rotate_right(self) -> Self {
+        Self {
+            rows: self.cols,
+            cols: self.rows,
+            origin: self.origin + self.row_step * self.last_row(),
+            row_step: self.col_step,
+            col_step: -self.row_step,
+            ..self
+        }
+    }
+
+    pub fn rotate_left(self) -> Self {
+        Self {
+            rows: self.cols,
+            cols: self.rows,
+            origin: self.origin + self.col_step * self.last_col(),
+            row_step: -self.col_step,
+            col_step: self.row_step,
+            ..self
+        }
+    }
+
+    pub fn rotate_180(self) -> Self {
+        self.flip_rows().flip_cols()
+    }
+
+    // upside down, the last row comes first
+    pub fn flip_rows(self) -> Self {
+        Self {
+            origin: self.origin + self.row_step * self.last_row(),
+            row_step: -self.row_step,
+            ..self
+        }
+    }
+
+    // mirrored left to right, the last column comes first
+    pub fn flip_cols(self) -> Self {
+        Self {
+            origin: self.origin + self.col_step * self.last_col(),
+            col_step: -self.col_step,
+            ..self
+        }
+    }
+
+    // the rows x cols window with top_left as its first cell, None if it doesn't fit
+    pub fn crop(self, top_left: BoundVector2D, rows: usize, cols: usize) -> Option<Self> {
+        let top_left = top_left.bound(self.rows, self.cols)?;
+        (top_left.x + rows <= self.rows && top_left.y + cols <= self.cols).then_some(Self {
+            rows,
+            cols,
+            origin: self.origin
+                + self.row_step * top_left.x as isize
+                + self.col_step * top_left.y as isize,
+            ..self
+        })
+    }
+
+    pub fn iter_indices(self) -> impl Iterator<Item = BoundVector2D> + 'a {
+        (0..self.rows).flat_map(move |x| {
+            (0..self.cols).map(move |y| BoundVector2D::new(x, y, self.rows, self.cols).unwrap())
+        })
+    }
+
+    pub fn iter(self) -> impl Iterator<Item = (BoundVector2D, &'a T)> + 'a {
+        self.iter_indices()
+            .map(move |idx| (idx, self.get(idx).unwrap()))
+    }
+
+    // the cells from start in steps until the edge of the view
+    fn line(
+        self,
+        start: (usize, usize),
+        step: Vector2D,
+    ) -> impl Iterator<Item = (BoundVector2D, &'a T)> + 'a {
+        let first = BoundVector2D::new(start.0, start.1, self.rows, self.cols);
+        iter::successors(first, move |&idx| idx + step)
+            .map(move |idx| (idx, self.get(idx).unwrap()))
+    }
+
+    pub fn rows(
+        self,
+    ) -> impl Iterator<Item = impl Iterator<Item = (BoundVector2D, &'a T)> + 'a> + 'a {
+        (0..self.rows).map(move |x| self.line((x, 0), Vector2D::new(0, 1)))
+    }
+
+    pub fn cols(
+        self,
+    ) -> impl Iterator<Item = impl Iterator<Item = (BoundVector2D, &'a T)> + 'a> + 'a {
+        (0..self.cols).map(move |y| self.line((0, y), Vector2D::new(1, 0)))
+    }
+
+    // the lines running down and forward, from the top right corner to the bottom left
+    pub fn diagonals(
+        self,
+    ) -> impl Iterator<Item = impl Iterator<Item = (BoundVector2D, &'a T)> + 'a> + 'a {
+        let top = (0..self.cols).rev().map(|y| (0, y));
+        let left = (1..self.rows).map(|x| (x, 0));
+        top.chain(left)
+            .map(move |start| self.line(start, Vector2D::new(1, 1)))
+    }
+
+    // the lines running down and backward, from the top left corner to the bottom right
+    pub fn anti_diagonals(
+        self,
+    ) -> impl Iterator<Item = impl Iterator<Item = (BoundVector2D, &'a T)> + 'a> + 'a {
+        let last_col = self.cols.saturating_sub(1);
+        let top = (0..self.cols).map(|y| (0, y));
+        let right = (1..self.rows)
+            .filter(move |_| self.cols > 0)
+            .map(move |x| (x, last_col));
+        top.chain(right)
+            .map(move |start| self.line(start, Vector2D::new(1, -1)))
+    }
+
+    pub fn to_grid(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid::from_fn(self.rows, self.cols, |idx| self[idx].clone())
+    }
+}
+
+impl<T> Index<BoundVector2D> for View<'_, T> {
+    type Output = T;
+
+    fn index(&self, index: BoundVector2D) -> &Self::Output {
+        self.get(index).unwrap()
+    }
+}
+
+impl<T> Grid<T> {
+    pub fn view(&self) -> View<'_, T> {
+        View::new(self)
+    }
+
+    pub fn transpose(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.view().transpose().to_grid()
+    }
+
+    pub fn rotate_right(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.view().rotate_right().to_grid()
+    }
+
+    pub fn rotate_left(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.view().rotate_left().to_grid()
+    }
+
+    pub fn flip_rows(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.view().flip_rows().to_grid()
+    }
+
+    pub fn flip_cols(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.view().flip_cols().to_grid()
+    }
+
+    pub fn crop(&self, top_left: BoundVector2D, rows: usize, cols: usize) -> Option<Self>
+    where
+        T: Clone,
+    {
+        Some(self.view().crop(top_left, rows, cols)?.to_grid())
+    }
+
+    // the grid repeated down times below and across times beside itself
+    pub fn tile(&self, down: usize, across: usize) -> Self
+    where
+        T: Clone,
+    {
+        Grid::from_fn(self.rows * down, self.cols * across, |idx| {
+            let src =
+                BoundVector2D::new(idx.x % self.rows, idx.y % self.cols, self.rows, self.cols);
+            self[src.unwrap()].clone()
+        })
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = impl Iterator<Item = (BoundVector2D, &T)>> {
+        self.view().rows()
+    }
+
+    pub fn cols(&self) -> impl Iterator<Item = impl Iterator<Item = (BoundVector2D, &T)>> {
+        self.view().cols()
+    }
+
+    pub fn diagonals(&self) -> impl Iterator<Item = impl Iterator<Item = (BoundVector2D, &T)>> {
+        self.view().diagonals()
+    }
+
+    pub fn anti_diagonals(
+        &self,
+    ) -> impl Iterator<Item = impl Iterator<Item = (BoundVector2D, &T)>> {
+        self.view().anti_diagonals()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::char_grid::CharGrid;
+
+    fn grid() -> CharGrid {
+        CharGrid::from_string("abc\ndef").unwrap()
+    }
+
+    fn idx(x: usize, y: usize) -> BoundVector2D {
+        BoundVector2D::new(x, y, 10, 10).unwrap()
+    }
+
+    fn lines<'a, L: Iterator<Item = (BoundVector2D, &'a char)>>(
+        lines: impl Iterator<Item = L>,
+    ) -> Vec<String> {
+        lines.map(|line| line.map(|(_, &c)| c).collect()).collect()
+    }
+
+    #[test]
+    fn test_identity_view() {
+        let grid = grid();
+        assert_eq!(grid.view().to_grid(), grid);
+    }
+
+    #[test]
+    fn test_transpose() {
+        assert_eq!(grid().transpose().to_string(), "ad\nbe\ncf");
+        assert_eq!(grid().transpose().transpose(), grid());
+    }
+
+    #[test]
+    fn test_rotate() {
+        assert_eq!(grid().rotate_right().to_string(), "da\neb\nfc");
+        assert_eq!(grid().rotate_left().to_string(), "cf\nbe\nad");
+        assert_eq!(grid().view().rotate_180().to_grid().to_string(), "fed\ncba");
+        let grid = grid();
+        let view = grid.view();
+        let full_turn = view
+            .rotate_right()
+            .rotate_right()
+            .rotate_right()
+            .rotate_right();
+        assert_eq!(full_turn.to_grid(), grid);
+        assert_eq!(view.rotate_right().rotate_left().to_grid(), grid);
+    }
+
+    #[test]
+    fn test_flip() {
+        assert_eq!(grid().flip_rows().to_string(), "def\nabc");
+        assert_eq!(grid().flip_cols().to_string(), "cba\nfed");
+    }
+
+    #[test]
+    fn test_crop() {
+        let grid = CharGrid::from_string("abcd\nefgh\nijkl").unwrap();
+        assert_eq!(grid.crop(idx(1, 1), 2, 2).unwrap().to_string(), "fg\njk");
+        assert_eq!(grid.crop(idx(1, 1), 2, 4), None);
+        assert_eq!(grid.crop(idx(3, 0), 0, 0), None);
+    }
+
+    #[test]
+    fn test_view_source() {
+        let grid = CharGrid::from_string("abcd\nefgh\nijkl").unwrap();
+        let view = grid.view().crop(idx(1, 1), 2, 3).unwrap().rotate_right();
+        assert_eq!(view.to_grid().to_string(), "jf\nkg\nlh");
+        let source = view.source(idx(2, 0)).unwrap();
+        assert_eq!((source.x, source.y), (2, 3));
+        assert_eq!(view[idx(2, 0)], 'l');
+        assert_eq!(view.get(idx(0, 2)), None);
+    }
+
+    #[test]
+    fn test_view_iter() {
+        let grid = grid();
+        let cells: Vec<_> = grid
+            .view()
+            .flip_cols()
+            .iter()
+            .map(|(i, &c)| (i.x, i.y, c))
+            .collect();
+        assert_eq!(cells[0], (0, 0, 'c'));
+        assert_eq!(cells[5], (1, 2, 'd'));
+    }
+
+    #[test]
+    fn test_tile() {
+        let grid = CharGrid::from_string("ab").unwrap();
+        assert_eq!(grid.tile(2, 3).to_string(), "ababab\nababab");
+        assert_eq!(grid.tile(0, 3).rows, 0);
+    }
+
+    #[test]
+    fn test_rows_and_cols() {
+        assert_eq!(lines(grid().rows()), vec!["abc", "def"]);
+        assert_eq!(lines(grid().cols()), vec!["ad", "be", "cf"]);
+    }
+
+    #[test]
+    fn test_diagonals() {
+        assert_eq!(lines(grid().diagonals()), vec!["c", "bf", "ae", "d"]);
+        assert_eq!(lines(grid().anti_diagonals()), vec!["a", "bd", "ce", "f"]);
+        let empty = CharGrid::from_string("").unwrap();
+        assert_eq!(empty.diagonals().count(), 0);
+        assert_eq!(empty.anti_diagonals().count(), 0);
+    }
+
+    #[test]
+    fn test_lines_of_transformed_view() {
+        let grid = grid();
+        let view = grid.view().rotate_right();
+        assert_eq!(lines(view.rows()), vec!["da", "eb", "fc"]);
+        assert_eq!(lines(view.diagonals()), vec!["a", "db", "ec", "f"]);
+    }
+}
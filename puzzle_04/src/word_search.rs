@@ -0,0 +1,237 @@
+use std::collections::HashMap;
+
+use crate::char_grid::CharGrid;
+use crate::direction::Direction;
+use crate::pattern::Pattern;
+use crate::vector::BoundVector2D;
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+struct Node {
+    children: HashMap<char, usize>,
+    // indices of the words ending here
+    words: Vec<usize>,
+}
+
+// the words to look for, stored as a trie so every start and direction is walked only once
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Dictionary {
+    words: Vec<String>,
+    nodes: Vec<Node>,
+}
+
+impl Dictionary {
+    // empty and repeated words are left out
+    pub fn new<S: Into<String>>(words: impl IntoIterator<Item = S>) -> Self {
+        let mut dictionary = Self {
+            words: vec![],
+            nodes: vec![Node::default()],
+        };
+        for word in words {
+            dictionary.insert(word.into());
+        }
+        dictionary
+    }
+
+    fn insert(&mut self, word: String) {
+        if word.is_empty() || self.words.contains(&word) {
+            return;
+        }
+        let mut node = 0;
+        for c in word.chars() {
+            node = match self.nodes[node].children.get(&c) {
+                Some(&child) => child,
+                None => {
+                    self.nodes.push(Node::default());
+                    let child = self.nodes.len() - 1;
+                    self.nodes[node].children.insert(c, child);
+                    child
+                }
+            };
+        }
+        self.nodes[node].words.push(self.words.len());
+        self.words.push(word);
+    }
+
+    pub fn words(&self) -> &[String] {
+        &self.words
+    }
+}
+
+impl CharGrid {
+    // every word read from a start cell in one of the eight directions,
+    // ordered by start, then direction, then length
+    pub fn find_words<'a>(
+        &self,
+        dictionary: &'a Dictionary,
+    ) -> Vec<(&'a str, BoundVector2D, Direction)> {
+        let mut hits = vec![];
+        for start in self.iter_indices() {
+            for direction in Direction::variants() {
+                let mut node = 0;
+                for (_, c) in self.ray(start, direction) {
+                    let Some(&child) = dictionary.nodes[node].children.get(c) else {
+                        break;
+                    };
+                    node = child;
+                    for &word in &dictionary.nodes[node].words {
+                        hits.push((dictionary.words[word].as_str(), start, direction.clone()));
+                    }
+                }
+            }
+        }
+        hits
+    }
+}
+
+pub fn count_str(s: &str, grid: &CharGrid) -> usize {
+    grid.find_words(&Dictionary::new([s])).len()
+}
+
+// the word on both diagonals of a square, crossing in the middle letter
+fn x_shape(s: &str) -> Option<Pattern> {
+    let word: Vec<char> = s.chars().collect();
+    let n = word.len();
+    if n.is_multiple_of(2) {
+        return None;
+    }
+    let template = (0..n)
+        .map(|x| {
+            (0..n)
+                .map(|y| {
+                    if y == x || y == n - 1 - x {
+                        word[x]
+                    } else {
+                        '.'
+                    }
+                })
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+    Pattern::from_string(&template).ok()
+}
+
+// the rotations read each diagonal in both directions
+pub fn count_x_shape(s: &str, grid: &CharGrid) -> usize {
+    x_shape(s).map_or(0, |pattern| {
+        pattern
+            .rotations()
+            .iter()
+            .map(|rotation| grid.find_pattern(rotation).len())
+            .sum()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_upward() {
+        let grid = CharGrid::from_string("S\nA\nM\nX").unwrap();
+        let result = count_str("XMAS", &grid);
+        assert_eq!(result, 1);
+    }
+
+    #[test]
+    fn test_forward() {
+        let grid = CharGrid::from_string("XMAS").unwrap();
+        let result = count_str("XMAS", &grid);
+        assert_eq!(result, 1);
+    }
+
+    #[test]
+    fn test_diagonals_both_ways() {
+        let grid = CharGrid::from_string("X..S\n.MA.\n.MA.\nX..S").unwrap();
+        let result = count_str("XMAS", &grid);
+        assert_eq!(result, 2);
+        let result = count_str("SAMX", &grid);
+        assert_eq!(result, 2);
+    }
+
+    #[test]
+    fn test_palindrome_counts_every_direction() {
+        let grid = CharGrid::from_string("ABA").unwrap();
+        assert_eq!(count_str("ABA", &grid), 2);
+        assert_eq!(count_str("B", &grid), 8);
+    }
+
+    #[test]
+    fn test_dictionary() {
+        let dictionary = Dictionary::new(["XMAS", "", "XMAS", "MAS"]);
+        assert_eq!(dictionary.words(), &["XMAS", "MAS"]);
+    }
+
+    #[test]
+    fn test_find_words() {
+        let grid = CharGrid::from_string("XMAS\n.A..\n..S.").unwrap();
+        let dictionary = Dictionary::new(["XMAS", "MAS", "SAM", "XA"]);
+        let hits: Vec<_> = grid
+            .find_words(&dictionary)
+            .into_iter()
+            .map(|(word, start, direction)| (word, start.x, start.y, direction))
+            .collect();
+        assert_eq!(
+            hits,
+            vec![
+                ("XMAS", 0, 0, Direction::Forward),
+                ("XA", 0, 0, Direction::Downforward),
+                ("MAS", 0, 1, Direction::Forward),
+                ("SAM", 0, 3, Direction::Backward),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_find_words_prefixes() {
+        let grid = CharGrid::from_string("abcd").unwrap();
+        let dictionary = Dictionary::new(["abcd", "ab", "abc", "dc"]);
+        let words: Vec<_> = grid
+            .find_words(&dictionary)
+            .into_iter()
+            .map(|(word, _, _)| word)
+            .collect();
+        assert_eq!(words, vec!["ab", "abc", "abcd", "dc"]);
+    }
+
+    #[test]
+    fn test_find_words_highlight() {
+        let grid = CharGrid::from_string("..X\n.M.\nA..").unwrap();
+        let dictionary = Dictionary::new(["XMA"]);
+        let hits = grid.find_words(&dictionary);
+        assert_eq!(hits.len(), 1);
+        let (word, start, direction) = &hits[0];
+        let cells: Vec<_> = grid
+            .ray(*start, direction)
+            .take(word.chars().count())
+            .map(|(idx, _)| (idx.x, idx.y))
+            .collect();
+        assert_eq!(cells, vec![(0, 2), (1, 1), (2, 0)]);
+    }
+
+    #[test]
+    fn test_x_shape() {
+        let grid = CharGrid::from_string("M.S\n.A.\nM.S").unwrap();
+        assert_eq!(count_x_shape("MAS", &grid), 1);
+        let grid = CharGrid::from_string("S.S\n.A.\nM.M").unwrap();
+        assert_eq!(count_x_shape("MAS", &grid), 1);
+        let grid = CharGrid::from_string("M.M\n.A.\nM.S").unwrap();
+        assert_eq!(count_x_shape("MAS", &grid), 0);
+    }
+
+    #[test]
+    fn test_x_shape_longer_words() {
+        let grid = CharGrid::from_string("X...S\n.M.A.\n..A..\n.M.A.\nX...S").unwrap();
+        assert_eq!(count_x_shape("XMAAS", &grid), 1);
+        assert_eq!(count_x_shape("XMAS", &grid), 0);
+        let grid = CharGrid::from_string("S...S\n.A.A.\n..M..\n.A.A.\nS...S").unwrap();
+        assert_eq!(count_x_shape("SAMAS", &grid), 1);
+    }
+
+    #[test]
+    fn test_downward() {
+        let grid = CharGrid::from_string("X\nM\nA\nS").unwrap();
+        let result = count_str("XMAS", &grid);
+        assert_eq!(result, 1);
+    }
+}
@@ -0,0 +1,120 @@
+use std::{cmp::Ordering, fmt::Display};
+
+use aoc_core::Solution;
+pub use aoc_parse::ParseError;
+use aoc_parse::{lines, Line};
+use ordering::{OrderItem, OrderingRule};
+
+pub mod ordering;
+
+pub type Input = (Vec<OrderingRule>, Vec<Vec<OrderItem>>);
+
+pub struct Puzzle05;
+
+pub fn parse(input: &str) -> Result<Input, ParseError> {
+    let mut lines = lines(input);
+    let rules = lines
+        .by_ref()
+        .take_while(|l| !l.is_blank())
+        .map(OrderingRule::parse)
+        .collect::<Result<_, _>>()?;
+    let sequences = lines
+        .filter(|l| !l.is_blank())
+        .map(create_sequence)
+        .collect::<Result<_, _>>()?;
+    Ok((rules, sequences))
+}
+
+fn create_sequence(line: Line) -> Result<Vec<OrderItem>, ParseError> {
+    line.separated(line.text, ',')
+}
+
+fn order_correctly(seq: &[OrderItem], rules: &[OrderingRule]) -> Vec<OrderItem> {
+    let compare_by_rules = |a: &OrderItem, b: &OrderItem| {
+        if rules
+            .iter()
+            .any(|r| r.before == *a && r.after == *b)
+        {
+            Ordering::Less
+        } else if rules
+            .iter()
+            .any(|r| r.before == *b && r.after == *a)
+        {
+            Ordering::Greater
+        } else {
+            Ordering::Equal
+        }
+    };
+
+    let mut s = seq.to_vec();
+
+    s.sort_by(compare_by_rules);
+    s
+}
+
+pub fn part1((rules, sequences): &Input) -> OrderItem {
+    sequences
+        .iter()
+        .filter(|s| rules.iter().all(|r| r.is_fullfilled(s)))
+        .map(|s| *s.get(s.len() / 2).unwrap())
+        .sum()
+}
+
+pub fn part2((rules, sequences): &Input) -> OrderItem {
+    sequences
+        .iter()
+        .filter(|s| rules.iter().any(|r| !r.is_fullfilled(s)))
+        .map(|s| order_correctly(s, rules))
+        .map(|s| *s.get(s.len() / 2).unwrap())
+        .sum()
+}
+
+impl Solution for Puzzle05 {
+    const DAY: u8 = 5;
+    const TITLE: &'static str = "Print Queue";
+
+    type Input = Input;
+    type Error = ParseError;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        parse(input)
+    }
+
+    fn part_1(input: &Self::Input) -> impl Display {
+        part1(input)
+    }
+
+    fn part_2(input: &Self::Input) -> impl Display {
+        part2(input)
+    }
+}
+
+aoc_core::fixture_tests!(Puzzle05);
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::answers::assert_answers;
+
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let (rules, sequences) = parse("47|53\n97|13\n\n75,47,61\n").unwrap();
+        assert_eq!(rules.len(), 2);
+        assert_eq!(sequences, vec![vec![75, 47, 61]]);
+    }
+
+    #[test]
+    fn test_parse_error_line() {
+        let result = parse("47|53\n\n75,47,61\n75,,61");
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "line 4, column 4: expected a number, found \"\""
+        );
+    }
+
+    #[test]
+    fn test_main() {
+        assert_answers::<Puzzle05>();
+    }
+}
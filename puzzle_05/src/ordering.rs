@@ -1,3 +1,5 @@
+use aoc_parse::{Line, ParseError};
+
 pub type OrderItem = usize;
 
 #[derive(Debug)]
@@ -7,13 +9,9 @@ pub struct OrderingRule {
 }
 
 impl OrderingRule {
-    pub fn from_str(s: &str) -> Option<OrderingRule> {
-        let mut parts = s.split('|');
-
-        let before = parts.next()?.parse().ok()?;
-        let after = parts.next()?.parse().ok()?;
-
-        Some(OrderingRule { before, after })
+    pub fn parse(line: Line) -> Result<OrderingRule, ParseError> {
+        let (before, after) = line.pair(line.text, '|')?;
+        Ok(OrderingRule { before, after })
     }
 
     pub fn is_fullfilled(&self, sequence: &[OrderItem]) -> bool {
@@ -29,55 +27,79 @@ impl OrderingRule {
 
 #[cfg(test)]
 mod tests {
+    use aoc_core::location::Location;
+
     use super::*;
 
+    fn rule(text: &str) -> OrderingRule {
+        OrderingRule::parse(Line::new(0, text)).unwrap()
+    }
+
     #[test]
     fn test_from_str() {
-        let result = OrderingRule::from_str("425|27").unwrap();
+        let result = rule("425|27");
 
         assert_eq!(result.before, 425);
         assert_eq!(result.after, 27);
     }
 
+    #[test]
+    fn test_parse_missing_separator() {
+        let result = OrderingRule::parse(Line::new(3, "425,27"));
+        assert_eq!(
+            result.unwrap_err(),
+            ParseError::Expected {
+                location: Location::new(4, 7),
+                expected: "'|'".to_owned()
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_invalid_number() {
+        let result = OrderingRule::parse(Line::new(0, "425|x"));
+        assert_eq!(
+            result.unwrap_err(),
+            ParseError::InvalidNumber {
+                location: Location::new(1, 5),
+                token: "x".to_owned()
+            }
+        );
+    }
+
     #[test]
     fn test_is_fullfilled_empty() {
-        let rule = OrderingRule::from_str("42|27").unwrap();
-        let result = rule.is_fullfilled(&[]);
+        let result = rule("42|27").is_fullfilled(&[]);
         assert!(result);
     }
 
     #[test]
     fn test_is_fullfilled_both_not_present() {
-        let rule = OrderingRule::from_str("42|27").unwrap();
-        let result = rule.is_fullfilled(&[1, 2, 3, 4]);
+        let result = rule("42|27").is_fullfilled(&[1, 2, 3, 4]);
         assert!(result);
     }
 
     #[test]
     fn test_is_fullfilled_before_not_present() {
-        let rule = OrderingRule::from_str("42|27").unwrap();
-        let result = rule.is_fullfilled(&[100, 27, 13]);
+        let result = rule("42|27").is_fullfilled(&[100, 27, 13]);
         assert!(result);
     }
 
     #[test]
     fn test_is_fullfilled_after_not_present() {
-        let rule = OrderingRule::from_str("42|27").unwrap();
-        let result = rule.is_fullfilled(&[10, 42, 13]);
+        let result = rule("42|27").is_fullfilled(&[10, 42, 13]);
         assert!(result);
     }
 
     #[test]
     fn test_is_fullfilled_correct() {
-        let rule = OrderingRule::from_str("42|27").unwrap();
-        let result = rule.is_fullfilled(&[10, 42, 13, 27]);
+        let result = rule("42|27").is_fullfilled(&[10, 42, 13, 27]);
         assert!(result);
     }
 
     #[test]
     fn test_is_fullfilled_false() {
-        let rule = OrderingRule::from_str("42|27").unwrap();
-        let result = rule.is_fullfilled(&[27, 10, 42, 13]);
+        let result = rule("42|27").is_fullfilled(&[27, 10, 42, 13]);
         assert!(!result);
     }
 }
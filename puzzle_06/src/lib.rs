@@ -0,0 +1,227 @@
+use std::collections::HashSet;
+use std::error::Error;
+use std::fmt::Display;
+
+use aoc_core::Solution;
+use puzzle_04::char_grid::CharGrid;
+use puzzle_04::direction::Direction;
+use puzzle_04::grid::{Grid, GridError};
+use puzzle_04::render::{Color, Overlay};
+use puzzle_04::simulation::{Agent, End, Simulation};
+use puzzle_04::vector::BoundVector2D;
+
+#[derive(Debug, Clone)]
+pub struct Lab {
+    pub obstacles: Grid<bool>,
+    pub start: BoundVector2D,
+    pub direction: Direction,
+}
+
+// the guard walking until the edge, turning right in front of obstacles and the extra barrier
+fn patrol(
+    lab: &Lab,
+    barrier: Option<BoundVector2D>,
+) -> Simulation<'_, bool, (), impl FnMut(&Grid<bool>, &Agent) -> Option<Agent>> {
+    let start = Agent::new(lab.start, lab.direction.clone());
+    Simulation::new(&lab.obstacles, start, move |obstacles, guard| {
+        let next = guard.ahead()?;
+        Some(if obstacles[next] || Some(next) == barrier {
+            guard.facing(guard.direction.turn_right())
+        } else {
+            guard.moved_to(next)
+        })
+    })
+}
+
+fn get_visited_positions(lab: &Lab) -> HashSet<BoundVector2D> {
+    patrol(lab, None).map(|guard| guard.pos).collect()
+}
+
+// the lab with the guard's path marked, shown by `aoc run --debug`
+pub fn render_visited(lab: &Lab, color: bool) -> String {
+    let grid = lab
+        .obstacles
+        .map(|&obstacle| if obstacle { '#' } else { '.' });
+    let path = Overlay::new(get_visited_positions(lab))
+        .with_char('X')
+        .with_color(Color::Yellow);
+    let renderer = grid.render().overlay(path);
+    if color {
+        renderer.to_string()
+    } else {
+        renderer.plain().to_string()
+    }
+}
+
+fn get_to_check(lab: &Lab, visited_positions: HashSet<BoundVector2D>) -> HashSet<BoundVector2D> {
+    let barriers: HashSet<_> = lab
+        .obstacles
+        .iter()
+        .filter(|(_, &obstacle)| obstacle)
+        .map(|(idx, _)| idx)
+        .collect();
+    let x_vals: HashSet<usize> = barriers.iter().map(|v| v.x).collect();
+    let y_vals: HashSet<usize> = barriers.iter().map(|v| v.y).collect();
+
+    visited_positions
+        .into_iter()
+        .filter(|idx| x_vals.contains(&idx.x) || y_vals.contains(&idx.y))
+        .filter(|idx| !barriers.contains(idx))
+        .filter(|idx| idx != &lab.start)
+        .collect()
+}
+
+fn is_loop(lab: &Lab, &barrier: &BoundVector2D) -> bool {
+    patrol(lab, Some(barrier)).run() == End::Loop
+}
+
+fn count_loops(lab: &Lab, visited_positions: HashSet<BoundVector2D>) -> usize {
+    get_to_check(lab, visited_positions)
+        .iter()
+        .filter(|idx| is_loop(lab, idx))
+        .count()
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum ParseError {
+    Grid(GridError),
+    MissingGuard,
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Grid(e) => write!(f, "{}", e),
+            Self::MissingGuard => write!(f, "expected a guard ('^', '>', 'v' or '<') in the grid"),
+        }
+    }
+}
+
+impl Error for ParseError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Grid(e) => Some(e),
+            Self::MissingGuard => None,
+        }
+    }
+}
+
+impl From<GridError> for ParseError {
+    fn from(e: GridError) -> Self {
+        Self::Grid(e)
+    }
+}
+
+pub type Input = Lab;
+
+pub struct Puzzle06;
+
+pub fn parse(input: &str) -> Result<Input, ParseError> {
+    let grid = CharGrid::from_string(input)?;
+    let start = grid
+        .position(|&c| Direction::from_arrow(c).is_some())
+        .ok_or(ParseError::MissingGuard)?;
+    Ok(Lab {
+        obstacles: grid.map(|&c| c == '#'),
+        start,
+        direction: Direction::from_arrow(grid[start]).unwrap(),
+    })
+}
+
+pub fn part1(lab: &Input) -> usize {
+    get_visited_positions(lab).len()
+}
+
+pub fn part2(lab: &Input) -> usize {
+    let visited_positions = get_visited_positions(lab);
+    count_loops(lab, visited_positions)
+}
+
+impl Solution for Puzzle06 {
+    const DAY: u8 = 6;
+    const TITLE: &'static str = "Guard Gallivant";
+
+    type Input = Input;
+    type Error = ParseError;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        parse(input)
+    }
+
+    fn part_1(input: &Self::Input) -> impl Display {
+        part1(input)
+    }
+
+    fn part_2(input: &Self::Input) -> impl Display {
+        part2(input)
+    }
+
+    fn debug(input: &Self::Input, color: bool) -> Option<String> {
+        Some(render_visited(input, color))
+    }
+}
+
+aoc_core::fixture_tests!(Puzzle06);
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::answers::assert_answers;
+
+    use super::*;
+
+    #[test]
+    fn test_example_loop_1() {
+        let lab = parse(
+            ".#..
+...#
+.^#.",
+        )
+        .unwrap();
+
+        let result = count_loops(&lab, lab.obstacles.iter_indices().collect());
+
+        assert_eq!(result, 1);
+    }
+    #[test]
+    fn test_example_loop_2() {
+        let lab = parse(
+            ".#.
+.^#
+.#.",
+        )
+        .unwrap();
+
+        let result = count_loops(&lab, lab.obstacles.iter_indices().collect());
+
+        assert_eq!(result, 1);
+    }
+
+    #[test]
+    fn test_render_visited() {
+        let lab = parse(include_str!("../fixtures/example.txt")).unwrap();
+        assert_eq!(
+            render_visited(&lab, false),
+            "....#.....
+....XXXXX#
+....X...X.
+..#.X...X.
+..XXXXX#X.
+..X.X.X.X.
+.#XXXXXXX.
+.XXXXXXX#.
+#XXXXXXX..
+......#X.."
+        );
+    }
+
+    #[test]
+    fn test_parse_missing_guard() {
+        let result = Puzzle06::parse("..#\n...");
+        assert_eq!(result.unwrap_err(), ParseError::MissingGuard);
+    }
+
+    #[test]
+    fn test_main() {
+        assert_answers::<Puzzle06>();
+    }
+}
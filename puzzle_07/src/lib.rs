@@ -0,0 +1,188 @@
+use std::fmt::Display;
+
+use aoc_core::Solution;
+pub use aoc_parse::ParseError;
+use aoc_parse::{non_blank_lines, Line};
+
+pub type CalculationType = isize;
+pub type Equation = (CalculationType, Vec<CalculationType>);
+
+fn parse_equation(line: Line) -> Result<Equation, ParseError> {
+    line.target()
+}
+
+pub type Input = Vec<Equation>;
+
+pub struct Puzzle07;
+
+pub fn parse(input: &str) -> Result<Input, ParseError> {
+    non_blank_lines(input).map(parse_equation).collect()
+}
+
+pub fn part1(equations: &Input) -> CalculationType {
+    equations
+        .iter()
+        .filter(|(result, operands)| could_be_calculated(result, &operands[1..], operands[0]))
+        .map(|(result, _)| result)
+        .sum::<CalculationType>()
+}
+
+pub fn part2(equations: &Input) -> CalculationType {
+    equations
+        .iter()
+        .filter(|(result, operands)| {
+            could_be_calculated_with_concat(result, &operands[1..], operands[0])
+        })
+        .map(|(result, _)| result)
+        .sum::<CalculationType>()
+}
+
+impl Solution for Puzzle07 {
+    const DAY: u8 = 7;
+    const TITLE: &'static str = "Bridge Repair";
+
+    type Input = Input;
+    type Error = ParseError;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        parse(input)
+    }
+
+    fn part_1(input: &Self::Input) -> impl Display {
+        part1(input)
+    }
+
+    fn part_2(input: &Self::Input) -> impl Display {
+        part2(input)
+    }
+}
+
+fn could_be_calculated(
+    result: &CalculationType,
+    operands: &[CalculationType],
+    cur_result: CalculationType,
+) -> bool {
+    if operands.is_empty() {
+        cur_result == *result
+    } else if cur_result > *result {
+        false
+    } else {
+        let operator = operands.first().unwrap();
+        could_be_calculated(result, &operands[1..], cur_result + operator)
+            || could_be_calculated(result, &operands[1..], cur_result * operator)
+    }
+}
+
+fn concat_vals(op1: &CalculationType, op2: &CalculationType) -> CalculationType {
+    (op1.to_string() + &op2.to_string()).parse().unwrap()
+}
+
+fn could_be_calculated_with_concat(
+    result: &CalculationType,
+    operands: &[CalculationType],
+    cur_result: CalculationType,
+) -> bool {
+    if operands.is_empty() {
+        cur_result == *result
+    } else if cur_result > *result {
+        false
+    } else {
+        let operator = operands.first().unwrap();
+        could_be_calculated_with_concat(result, &operands[1..], cur_result + operator)
+            || could_be_calculated_with_concat(result, &operands[1..], cur_result * operator)
+            || could_be_calculated_with_concat(
+                result,
+                &operands[1..],
+                concat_vals(&cur_result, operator),
+            )
+    }
+}
+
+aoc_core::fixture_tests!(Puzzle07);
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::{answers::assert_answers, location::Location};
+
+    use super::*;
+
+    #[test]
+    fn test_parse_equation() {
+        let result = parse_equation(Line::new(0, "3267: 81 40 27"));
+        assert_eq!(result, Ok((3267, vec![81, 40, 27])));
+    }
+
+    #[test]
+    fn test_parse_equation_missing_colon() {
+        let result = parse_equation(Line::new(4, "3267 81 40 27"));
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "line 5, column 14: expected ':'"
+        );
+    }
+
+    #[test]
+    fn test_parse_equation_invalid_operand() {
+        let result = parse_equation(Line::new(0, "3267: 81 4o 27"));
+        assert_eq!(
+            result,
+            Err(ParseError::InvalidNumber {
+                location: Location::new(1, 10),
+                token: "4o".to_owned()
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_equation_missing_operands() {
+        let result = parse_equation(Line::new(0, "3267:"));
+        assert_eq!(
+            result,
+            Err(ParseError::Expected {
+                location: Location::new(1, 6),
+                expected: "at least one number".to_owned()
+            })
+        );
+    }
+
+    #[test]
+    fn test_example_1() {
+        let result = could_be_calculated(&190, &[19], 10);
+        assert!(result);
+    }
+
+    #[test]
+    fn test_example_2() {
+        let result = could_be_calculated(&3267, &[40, 27], 81);
+        assert!(result);
+    }
+
+    #[test]
+    fn test_example_3() {
+        let result = could_be_calculated(&292, &[6, 16, 20], 11);
+        assert!(result);
+    }
+
+    #[test]
+    fn test_concat_vals() {
+        let result = concat_vals(&11, &2);
+        assert_eq!(result, 112);
+    }
+
+    #[test]
+    fn test_concat_example_1() {
+        let result = could_be_calculated_with_concat(&156, &[6], 15);
+        assert!(result);
+    }
+
+    #[test]
+    fn test_concat_example_2() {
+        let result = could_be_calculated_with_concat(&7290, &[8, 6, 15], 6);
+        assert!(result);
+    }
+
+    #[test]
+    fn test_main() {
+        assert_answers::<Puzzle07>();
+    }
+}
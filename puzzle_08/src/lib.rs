@@ -0,0 +1,149 @@
+use std::collections::HashSet;
+use std::fmt::Display;
+
+use aoc_core::Solution;
+use itertools::Itertools;
+
+use puzzle_04::char_grid::{CharGrid, GridError};
+use puzzle_04::render::{Color, Overlay};
+use puzzle_04::vector::BoundVector2D;
+
+fn get_anti_nodes(
+    vec_a: &BoundVector2D,
+    vec_b: &BoundVector2D,
+) -> impl Iterator<Item = BoundVector2D> {
+    let dir = vec_a.to_vector() - vec_b.to_vector();
+    let anti_a = *vec_a + dir;
+    let anti_b = *vec_b + -dir;
+
+    anti_a.into_iter().chain(anti_b)
+}
+
+// every cell in line with both antennas, walking away from each of them to the edge
+fn get_harmonic_anti_nodes(
+    grid: &CharGrid,
+    vec_a: &BoundVector2D,
+    vec_b: &BoundVector2D,
+) -> HashSet<BoundVector2D> {
+    let dir = vec_a.to_vector() - vec_b.to_vector();
+
+    grid.ray_step(*vec_a, dir.into())
+        .chain(grid.ray_step(*vec_b, (-dir).into()))
+        .map(|(idx, _)| idx)
+        .collect()
+}
+
+fn get_all_anti_nodes(grid: &CharGrid) -> (HashSet<BoundVector2D>, HashSet<BoundVector2D>) {
+    let mut anti_nodes = HashSet::new();
+    let mut harmonic_anti_nodes = HashSet::new();
+    let groups = grid.iter_indices().into_group_map_by(|idx| grid.get(*idx));
+    for (char, indices) in groups.iter() {
+        if char.is_none() || *char == Some(&'.') {
+            continue;
+        }
+        for comb in indices.iter().combinations(2) {
+            anti_nodes.extend(get_anti_nodes(comb[0], comb[1]));
+            harmonic_anti_nodes.extend(get_harmonic_anti_nodes(grid, comb[0], comb[1]));
+        }
+    }
+    (anti_nodes, harmonic_anti_nodes)
+}
+
+// the antinodes marked on empty cells, shown by `aoc run --debug`
+pub fn render_anti_nodes(grid: &CharGrid, harmonic: bool, color: bool) -> String {
+    let (anti_nodes, harmonic_anti_nodes) = get_all_anti_nodes(grid);
+    let anti_nodes = if harmonic {
+        harmonic_anti_nodes
+    } else {
+        anti_nodes
+    };
+    let marked = Overlay::new(anti_nodes.into_iter().filter(|&idx| grid[idx] == '.'))
+        .with_char('#')
+        .with_color(Color::Red);
+    let renderer = grid.render().overlay(marked);
+    if color {
+        renderer.to_string()
+    } else {
+        renderer.plain().to_string()
+    }
+}
+
+pub type Input = CharGrid;
+
+pub struct Puzzle08;
+
+pub fn parse(input: &str) -> Result<Input, GridError> {
+    CharGrid::from_string(input)
+}
+
+pub fn part1(grid: &Input) -> usize {
+    let (anti_nodes, _) = get_all_anti_nodes(grid);
+    anti_nodes.len()
+}
+
+pub fn part2(grid: &Input) -> usize {
+    let (_, harm_anti_nodes) = get_all_anti_nodes(grid);
+    harm_anti_nodes.len()
+}
+
+impl Solution for Puzzle08 {
+    const DAY: u8 = 8;
+    const TITLE: &'static str = "Resonant Collinearity";
+
+    type Input = Input;
+    type Error = GridError;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        parse(input)
+    }
+
+    fn part_1(input: &Self::Input) -> impl Display {
+        part1(input)
+    }
+
+    fn part_2(input: &Self::Input) -> impl Display {
+        part2(input)
+    }
+
+    fn debug(input: &Self::Input, color: bool) -> Option<String> {
+        Some(format!(
+            "{}\n\n{}",
+            render_anti_nodes(input, false, color),
+            render_anti_nodes(input, true, color)
+        ))
+    }
+}
+
+aoc_core::fixture_tests!(Puzzle08);
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::answers::assert_answers;
+
+    use super::*;
+
+    #[test]
+    fn test_render_anti_nodes() {
+        let grid = parse(include_str!("../fixtures/example.txt")).unwrap();
+        assert_eq!(
+            render_anti_nodes(&grid, false, false),
+            "......#....#
+...#....0...
+....#0....#.
+..#....0....
+....0....#..
+.#....A.....
+...#........
+#......#....
+........A...
+.........A..
+..........#.
+..........#."
+        );
+    }
+
+    #[test]
+    fn test_main() {
+        assert_answers::<Puzzle08>();
+    }
+}
@@ -1,7 +1,8 @@
-use std::{
-    fs::File,
-    io::{BufRead, BufReader},
-};
+use std::fmt::Display;
+
+use aoc_core::Solution;
+use aoc_parse::first_line;
+pub use aoc_parse::ParseError;
 
 fn checksum(blocks: &[Option<usize>]) -> usize {
     blocks
@@ -80,21 +81,53 @@ fn compact_files(disk_map: &[usize]) -> usize {
     checksum(&blocks)
 }
 
-fn main() {
-    let reader = BufReader::new(File::open("input.txt").unwrap());
-    let line = reader.lines().next().unwrap().unwrap();
-    let disk_map: Vec<usize> = line
-        .chars()
-        .map(|c| c.to_string().parse::<usize>().unwrap())
-        .collect();
-    let checksum_compact = compact(&disk_map);
-    println!("Checksum compact: {}", checksum_compact);
-    let checksum_compact_files = compact_files(&disk_map);
-    println!("Checksum compact files: {}", checksum_compact_files);
+pub type Input = Vec<usize>;
+
+pub fn parse(input: &str) -> Result<Input, ParseError> {
+    // every character is a number of its own
+    let line = first_line(input)?;
+    line.text
+        .char_indices()
+        .map(|(i, c)| line.number(&line.text[i..i + c.len_utf8()]))
+        .collect()
+}
+
+pub struct Puzzle09;
+
+pub fn part1(disk_map: &Input) -> usize {
+    compact(disk_map)
+}
+
+pub fn part2(disk_map: &Input) -> usize {
+    compact_files(disk_map)
+}
+
+impl Solution for Puzzle09 {
+    const DAY: u8 = 9;
+    const TITLE: &'static str = "Disk Fragmenter";
+
+    type Input = Input;
+    type Error = ParseError;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        parse(input)
+    }
+
+    fn part_1(input: &Self::Input) -> impl Display {
+        part1(input)
+    }
+
+    fn part_2(input: &Self::Input) -> impl Display {
+        part2(input)
+    }
 }
 
+aoc_core::fixture_tests!(Puzzle09);
+
 #[cfg(test)]
 mod tests {
+    use aoc_core::{answers::assert_answers, location::Location};
+
     use super::*;
 
     #[test]
@@ -215,17 +248,32 @@ mod tests {
         assert_eq!(res, 46);
     }
 
+    #[test]
+    fn test_parse() {
+        let result = parse("12345\n");
+        assert_eq!(result, Ok(vec![1, 2, 3, 4, 5]));
+    }
+
+    #[test]
+    fn test_parse_invalid_digit() {
+        let result = parse("12a45");
+        assert_eq!(
+            result,
+            Err(ParseError::InvalidNumber {
+                location: Location::new(1, 3),
+                token: "a".to_owned()
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_empty() {
+        let result = parse("");
+        assert_eq!(result, Err(ParseError::EmptyInput));
+    }
+
     #[test]
     fn test_main() {
-        let reader = BufReader::new(File::open("input.txt").unwrap());
-        let line = reader.lines().next().unwrap().unwrap();
-        let disk_map: Vec<usize> = line
-            .chars()
-            .map(|c| c.to_string().parse::<usize>().unwrap())
-            .collect();
-        let checksum_compact = compact(&disk_map);
-        assert_eq!(checksum_compact, 6344673854800);
-        let checksum_compact_files = compact_files(&disk_map);
-        assert_eq!(checksum_compact_files, 6360363199987);
+        assert_answers::<Puzzle09>();
     }
 }
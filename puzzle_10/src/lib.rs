@@ -0,0 +1,124 @@
+use std::collections::HashSet;
+use std::fmt::Display;
+
+use aoc_core::Solution;
+use puzzle_04::grid::{Grid, GridError};
+use puzzle_04::vector::BoundVector2D;
+
+// impassable cells ('.' in the examples) have no height
+pub type Heights = Grid<Option<u8>>;
+
+fn sum_trailhead_end_scores(grid: &Heights) -> usize {
+    grid.iter_indices()
+        .map(|idx| get_trailhead_ends(grid, Some(idx), 0))
+        .map(|s| s.len())
+        .sum()
+}
+
+fn get_trailhead_ends(
+    grid: &Heights,
+    index: Option<BoundVector2D>,
+    next_val: u8,
+) -> HashSet<BoundVector2D> {
+    if !index.is_some_and(|idx| {
+        grid.get(idx)
+            .is_some_and(|&height| height == Some(next_val))
+    }) {
+        HashSet::new()
+    } else if next_val == 9 {
+        HashSet::from([index.unwrap()])
+    } else {
+        grid.neighbors4(index.unwrap())
+            .flat_map(|(_, n, _)| get_trailhead_ends(grid, Some(n), next_val + 1))
+            .collect()
+    }
+}
+
+fn count_unique_trailheads(grid: &Heights) -> usize {
+    grid.iter_indices()
+        .map(|idx| get_unique_trailhead_paths(grid, Some(idx), 0))
+        .sum()
+}
+
+fn get_unique_trailhead_paths(grid: &Heights, index: Option<BoundVector2D>, next_val: u8) -> usize {
+    if !index.is_some_and(|idx| {
+        grid.get(idx)
+            .is_some_and(|&height| height == Some(next_val))
+    }) {
+        0
+    } else if next_val == 9 {
+        1
+    } else {
+        grid.neighbors4(index.unwrap())
+            .map(|(_, n, _)| get_unique_trailhead_paths(grid, Some(n), next_val + 1))
+            .sum()
+    }
+}
+
+pub type Input = Heights;
+
+pub struct Puzzle10;
+
+pub fn parse(input: &str) -> Result<Input, GridError> {
+    Grid::try_from_string_with(input, |c| match c {
+        '.' => Some(None),
+        c => c.to_digit(10).map(|d| Some(d as u8)),
+    })
+}
+
+pub fn part1(grid: &Input) -> usize {
+    sum_trailhead_end_scores(grid)
+}
+
+pub fn part2(grid: &Input) -> usize {
+    count_unique_trailheads(grid)
+}
+
+impl Solution for Puzzle10 {
+    const DAY: u8 = 10;
+    const TITLE: &'static str = "Hoof It";
+
+    type Input = Input;
+    type Error = GridError;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        parse(input)
+    }
+
+    fn part_1(input: &Self::Input) -> impl Display {
+        part1(input)
+    }
+
+    fn part_2(input: &Self::Input) -> impl Display {
+        part2(input)
+    }
+}
+
+aoc_core::fixture_tests!(Puzzle10);
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::answers::assert_answers;
+
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let grid = parse("0.\n98\n").unwrap();
+        assert_eq!(grid.cells(), &[Some(0), None, Some(9), Some(8)]);
+    }
+
+    #[test]
+    fn test_parse_invalid_height() {
+        let result = parse("01\n2#\n");
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "line 2, column 2: unexpected character '#'"
+        );
+    }
+
+    #[test]
+    fn test_main() {
+        assert_answers::<Puzzle10>();
+    }
+}
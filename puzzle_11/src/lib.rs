@@ -0,0 +1,131 @@
+use std::{collections::HashMap, fmt::Display};
+
+use aoc_core::Solution;
+use aoc_parse::first_line;
+pub use aoc_parse::ParseError;
+
+fn blink_stone(v: usize) -> Vec<usize> {
+    if v == 0 {
+        vec![1]
+    } else if v.ilog10() as usize % 2 == 1 {
+        let len = v.ilog10() as usize + 1;
+        let divisor = 10_usize.pow((len / 2) as u32);
+        let left = v / divisor;
+        let right = v % divisor;
+        vec![left, right]
+    } else {
+        vec![v * 2024]
+    }
+}
+
+// the memo is keyed by (stone, blinks left) and lives for one count only,
+// so repeated runs, like the benchmarks, redo the work every time
+fn count_stone(stone: usize, n: usize, memo: &mut HashMap<(usize, usize), usize>) -> usize {
+    if n == 0 {
+        return 1;
+    }
+    if let Some(&count) = memo.get(&(stone, n)) {
+        return count;
+    }
+    let count = blink_stone(stone)
+        .into_iter()
+        .map(|v| count_stone(v, n - 1, memo))
+        .sum();
+    memo.insert((stone, n), count);
+    count
+}
+
+fn count_stones_after_blinks(stones: &[usize], n: usize) -> usize {
+    let mut memo = HashMap::new();
+    stones.iter().map(|&v| count_stone(v, n, &mut memo)).sum()
+}
+
+pub type Input = Vec<usize>;
+
+pub fn parse(input: &str) -> Result<Input, ParseError> {
+    first_line(input)?.numbers()
+}
+
+pub struct Puzzle11;
+
+pub fn part1(stones: &Input) -> usize {
+    count_stones_after_blinks(stones, 25)
+}
+
+pub fn part2(stones: &Input) -> usize {
+    count_stones_after_blinks(stones, 75)
+}
+
+impl Solution for Puzzle11 {
+    const DAY: u8 = 11;
+    const TITLE: &'static str = "Plutonian Pebbles";
+
+    type Input = Input;
+    type Error = ParseError;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        parse(input)
+    }
+
+    fn part_1(input: &Self::Input) -> impl Display {
+        part1(input)
+    }
+
+    fn part_2(input: &Self::Input) -> impl Display {
+        part2(input)
+    }
+}
+
+aoc_core::fixture_tests!(Puzzle11);
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::answers::assert_answers;
+
+    use super::*;
+
+    #[test]
+    fn test_next_blink() {
+        let result = count_stones_after_blinks(&[125, 17], 1);
+        assert_eq!(result, 3);
+    }
+
+    #[test]
+    fn test_count_stones_after_blinks() {
+        assert_eq!(count_stones_after_blinks(&[125, 17], 6), 22);
+        assert_eq!(count_stones_after_blinks(&[125, 17], 25), 55312);
+        assert_eq!(count_stones_after_blinks(&[], 25), 0);
+    }
+
+    #[test]
+    fn test_blink_stone() {
+        let stone = blink_stone(125);
+        assert_eq!(stone, vec![253000]);
+    }
+
+    #[test]
+    fn test_blink_stone_2() {
+        let stone = blink_stone(17);
+        assert_eq!(stone, vec![1, 7]);
+    }
+
+    #[test]
+    fn test_parse() {
+        let result = parse("125 17\n");
+        assert_eq!(result, Ok(vec![125, 17]));
+    }
+
+    #[test]
+    fn test_parse_invalid_number() {
+        let result = parse("125 -17");
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "line 1, column 5: expected a number, found \"-17\""
+        );
+    }
+
+    #[test]
+    fn test_main() {
+        assert_answers::<Puzzle11>();
+    }
+}
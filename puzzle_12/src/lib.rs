@@ -0,0 +1,160 @@
+use std::collections::HashSet;
+use std::fmt::Display;
+
+use aoc_core::Solution;
+use puzzle_04::char_grid::{CharGrid, GridError};
+use puzzle_04::direction::Direction;
+use puzzle_04::vector::BoundVector2D;
+
+pub type Input = CharGrid;
+
+pub struct Puzzle12;
+
+pub fn parse(input: &str) -> Result<Input, GridError> {
+    CharGrid::from_string(input)
+}
+
+pub fn part1(grid: &Input) -> usize {
+    calculate_fence_price(grid)
+}
+
+pub fn part2(grid: &Input) -> usize {
+    calculate_fence_price_2(grid)
+}
+
+impl Solution for Puzzle12 {
+    const DAY: u8 = 12;
+    const TITLE: &'static str = "Garden Groups";
+
+    type Input = Input;
+    type Error = GridError;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        parse(input)
+    }
+
+    fn part_1(input: &Self::Input) -> impl Display {
+        part1(input)
+    }
+
+    fn part_2(input: &Self::Input) -> impl Display {
+        part2(input)
+    }
+}
+
+// the cells of a region and the side of each cell that borders another region or the edge
+fn fences(grid: &CharGrid) -> Vec<(usize, HashSet<(BoundVector2D, Direction)>)> {
+    let regions = &grid.regions(|a, b| a == b);
+    regions
+        .iter()
+        .map(|cells| {
+            let fences = cells
+                .iter()
+                .flat_map(|&idx| {
+                    grid.neighbors4_with_edges(idx)
+                        .filter(move |(_, n)| {
+                            n.is_none_or(|(n, _)| regions.label(n) != regions.label(idx))
+                        })
+                        .map(move |(dir, _)| (idx, dir))
+                })
+                .collect();
+            (cells.len(), fences)
+        })
+        .collect()
+}
+
+fn calculate_fence_price(grid: &CharGrid) -> usize {
+    fences(grid)
+        .iter()
+        .map(|(size, fences)| size * fences.len())
+        .sum()
+}
+
+fn calculate_fence_price_2(grid: &CharGrid) -> usize {
+    fences(grid)
+        .iter()
+        .map(|(size, fences)| size * calculate_sides(fences))
+        .sum()
+}
+
+// a side is a run of fences facing the same way along one line,
+// so every side has exactly one fence without a neighbor on its left
+fn calculate_sides(fences: &HashSet<(BoundVector2D, Direction)>) -> usize {
+    fences
+        .iter()
+        .filter(|(idx, dir)| {
+            (*idx + dir.turn_left().get_vector())
+                .is_none_or(|n| !fences.contains(&(n, dir.clone())))
+        })
+        .count()
+}
+
+aoc_core::fixture_tests!(Puzzle12);
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::answers::assert_answers;
+
+    use super::*;
+
+    #[test]
+    fn test_calculate_sides_1() {
+        let vec = BoundVector2D::new(0, 0, 1, 1).unwrap();
+        let fences = HashSet::from([
+            (vec, Direction::Down),
+            (vec, Direction::Forward),
+            (vec, Direction::Up),
+            (vec, Direction::Backward),
+        ]);
+
+        let result = calculate_sides(&fences);
+        assert_eq!(result, 4);
+    }
+
+    #[test]
+    fn test_calculate_sides_2() {
+        let vec1 = BoundVector2D::new(0, 0, 2, 2).unwrap();
+        let vec2 = BoundVector2D::new(1, 0, 2, 2).unwrap();
+        let fences = HashSet::from([
+            (vec1, Direction::Up),
+            (vec1, Direction::Backward),
+            (vec1, Direction::Forward),
+            (vec2, Direction::Down),
+            (vec2, Direction::Forward),
+            (vec2, Direction::Backward),
+        ]);
+
+        let result = calculate_sides(&fences);
+        assert_eq!(result, 4);
+    }
+
+    #[test]
+    fn test_calculate_sides_3() {
+        let vec1 = BoundVector2D::new(0, 0, 2, 2).unwrap();
+        let vec2 = BoundVector2D::new(0, 1, 2, 2).unwrap();
+        let vec3 = BoundVector2D::new(1, 1, 2, 2).unwrap();
+        let fences = HashSet::from([
+            (vec1, Direction::Up),
+            (vec1, Direction::Backward),
+            (vec1, Direction::Down),
+            (vec2, Direction::Up),
+            (vec2, Direction::Forward),
+            (vec3, Direction::Backward),
+            (vec3, Direction::Down),
+            (vec3, Direction::Forward),
+        ]);
+
+        let result = calculate_sides(&fences);
+        assert_eq!(result, 6);
+    }
+
+    #[test]
+    fn test_calculate_sides_empty() {
+        assert_eq!(calculate_sides(&HashSet::new()), 0);
+    }
+
+    #[test]
+    fn test_main() {
+        assert_answers::<Puzzle12>();
+    }
+}
@@ -0,0 +1,182 @@
+use std::fmt::Display;
+
+use aoc_core::Solution;
+pub use aoc_parse::ParseError;
+use aoc_parse::{lines_array, paragraphs, Line};
+use puzzle_04::vector::Vector2D;
+
+pub type Vector = Vector2D;
+pub type Machine = (Vector, Vector, Vector);
+
+pub type Input = Vec<Machine>;
+
+pub struct Puzzle13;
+
+pub fn part1(machines: &Input) -> isize {
+    machines
+        .iter()
+        .map(|&(move_a, move_b, goal_vec)| {
+            fewest_tokens_to_win_lin(move_a, move_b, goal_vec).unwrap_or(0)
+        })
+        .sum::<isize>()
+}
+
+pub fn part2(machines: &Input) -> isize {
+    machines
+        .iter()
+        .map(|&(move_a, move_b, goal_vec)| {
+            let goal_vec_2 = goal_vec + Vector::new(10000000000000, 10000000000000);
+            fewest_tokens_to_win_lin(move_a, move_b, goal_vec_2).unwrap_or(0)
+        })
+        .sum::<isize>()
+}
+
+impl Solution for Puzzle13 {
+    const DAY: u8 = 13;
+    const TITLE: &'static str = "Claw Contraption";
+
+    type Input = Input;
+    type Error = ParseError;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        parse(input)
+    }
+
+    fn part_1(input: &Self::Input) -> impl Display {
+        part1(input)
+    }
+
+    fn part_2(input: &Self::Input) -> impl Display {
+        part2(input)
+    }
+}
+
+pub fn parse(input: &str) -> Result<Input, ParseError> {
+    paragraphs(input)
+        .iter()
+        .map(|paragraph| {
+            let [a, b, prize] = lines_array(paragraph)?;
+            Ok((parse_vector(a)?, parse_vector(b)?, parse_vector(prize)?))
+        })
+        .collect()
+}
+
+fn parse_vector(line: Line) -> Result<Vector, ParseError> {
+    let [x, y] = line.signed_ints_array()?;
+    Ok(Vector::new(x, y))
+}
+
+fn fewest_tokens_to_win_lin(a: Vector, b: Vector, v: Vector) -> Option<isize> {
+    // v = na + mb, solved with Cramer's rule
+    let det = a.x * b.y - a.y * b.x;
+    // collinear buttons have no single solution, such machines count as unwinnable
+    if det == 0 {
+        return None;
+    }
+    let n = (v.x * b.y - v.y * b.x) / det;
+    let m = (a.x * v.y - a.y * v.x) / det;
+    (n >= 0 && m >= 0 && a * n + b * m == v).then_some(3 * n + m)
+}
+
+aoc_core::fixture_tests!(Puzzle13);
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::answers::assert_answers;
+
+    use super::*;
+
+    #[test]
+    fn test_example() {
+        let result = fewest_tokens_to_win_lin(
+            Vector::new(94, 34),
+            Vector::new(22, 67),
+            Vector::new(8400, 5400),
+        )
+        .unwrap();
+        assert_eq!(result, 280);
+    }
+
+    #[test]
+    fn test_no_integer_solution() {
+        let result = fewest_tokens_to_win_lin(
+            Vector::new(26, 66),
+            Vector::new(67, 21),
+            Vector::new(12748, 12176),
+        );
+        assert_eq!(result, None);
+    }
+
+    #[test]
+    fn test_button_without_x_movement() {
+        let result =
+            fewest_tokens_to_win_lin(Vector::new(0, 1), Vector::new(1, 0), Vector::new(3, 5));
+        assert_eq!(result, Some(18));
+    }
+
+    #[test]
+    fn test_collinear_buttons() {
+        let result =
+            fewest_tokens_to_win_lin(Vector::new(1, 1), Vector::new(2, 2), Vector::new(4, 4));
+        assert_eq!(result, None);
+    }
+
+    #[test]
+    fn test_negative_presses() {
+        let result =
+            fewest_tokens_to_win_lin(Vector::new(1, 0), Vector::new(0, 1), Vector::new(-1, 2));
+        assert_eq!(result, None);
+    }
+
+    #[test]
+    fn test_parse() {
+        let result = parse(
+            "Button A: X+94, Y+34
+Button B: X+22, Y+67
+Prize: X=8400, Y=5400
+
+Button A: X+26, Y+66
+Button B: X+67, Y+21
+Prize: X=12748, Y=12176
+",
+        );
+        assert_eq!(
+            result,
+            Ok(vec![
+                (
+                    Vector::new(94, 34),
+                    Vector::new(22, 67),
+                    Vector::new(8400, 5400)
+                ),
+                (
+                    Vector::new(26, 66),
+                    Vector::new(67, 21),
+                    Vector::new(12748, 12176)
+                )
+            ])
+        );
+    }
+
+    #[test]
+    fn test_parse_missing_coordinate() {
+        let result = parse("Button A: X+94, Y+34\nButton B: X+22\nPrize: X=8400, Y=5400\n");
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "line 2, column 15: expected 2 numbers"
+        );
+    }
+
+    #[test]
+    fn test_parse_unexpected_end() {
+        let result = parse("Button A: X+94, Y+34\nButton B: X+22, Y+67\n");
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "line 3, column 1: expected 3 lines"
+        );
+    }
+
+    #[test]
+    fn test_main() {
+        assert_answers::<Puzzle13>();
+    }
+}
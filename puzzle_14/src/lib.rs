@@ -0,0 +1,269 @@
+use std::{collections::HashSet, fmt::Display};
+
+use aoc_core::Solution;
+pub use aoc_parse::ParseError;
+use aoc_parse::{non_blank_lines, Line};
+use counter::Counter;
+use puzzle_04::char_grid::CharGrid;
+use puzzle_04::grid::Grid;
+use puzzle_04::vector::{BoundVector2D, Vector2D};
+
+pub type Vector = Vector2D;
+
+pub const SPACE: (usize, usize) = (101, 103);
+
+// the robots and the size of the space they move in, wide by tall
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Bathroom {
+    pub space: (usize, usize),
+    pub robots: Vec<(Vector, Vector)>,
+}
+
+pub type Input = Bathroom;
+
+pub struct Puzzle14;
+
+// an optional first line "space=W,H" overrides the size, the examples are 11 by 7
+pub fn parse(input: &str) -> Result<Input, ParseError> {
+    let mut lines = non_blank_lines(input).peekable();
+    let space = match lines.next_if(|line| line.text.starts_with("space=")) {
+        Some(line) => line.pair(line.value("space")?, ',')?,
+        None => SPACE,
+    };
+    let robots = lines.map(parse_pos_and_vel).collect::<Result<_, _>>()?;
+    Ok(Bathroom { space, robots })
+}
+
+pub fn part1(bathroom: &Input) -> usize {
+    let space = &bathroom.space;
+    let counter: Counter<_> = bathroom
+        .robots
+        .iter()
+        .map(|(pos, vel)| move_robot(pos, vel, &100, space))
+        .map(|pos| get_quadrant(&pos, space))
+        .collect();
+
+    counter.get(&1).unwrap_or(&0)
+        * counter.get(&2).unwrap_or(&0)
+        * counter.get(&3).unwrap_or(&0)
+        * counter.get(&4).unwrap_or(&0)
+}
+
+pub fn part2(bathroom: &Input) -> Option<isize> {
+    min_secs_to_christmas_tree(&bathroom.robots, &bathroom.space)
+}
+
+impl Solution for Puzzle14 {
+    const DAY: u8 = 14;
+    const TITLE: &'static str = "Restroom Redoubt";
+
+    type Input = Input;
+    type Error = ParseError;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        parse(input)
+    }
+
+    fn part_1(input: &Self::Input) -> impl Display {
+        part1(input)
+    }
+
+    fn part_2(input: &Self::Input) -> impl Display {
+        part2(input).map_or("no Christmas tree".to_owned(), |secs| secs.to_string())
+    }
+}
+
+fn min_secs_to_christmas_tree(
+    robots: &[(Vector, Vector)],
+    space: &(usize, usize),
+) -> Option<isize> {
+    // every robot is back where it started after width * height seconds
+    let period = (space.0 * space.1) as isize;
+    (0..period).find(|secs| {
+        let positions = robots
+            .iter()
+            .map(|(pos, vel)| move_robot(pos, vel, secs, space))
+            .collect();
+        is_christmas_tree(&positions, space)
+    })
+}
+
+fn is_christmas_tree(robots: &HashSet<BoundVector2D>, space: &(usize, usize)) -> bool {
+    let mut occupied = Grid::new(space.0, space.1, false);
+    for &pos in robots {
+        occupied[pos] = true;
+    }
+    let count = robots
+        .iter()
+        .filter(|&&pos| {
+            occupied
+                .neighbors4_wrapping(pos)
+                .any(|(_, _, &touching)| touching)
+        })
+        .count();
+    count > robots.len() / 2
+}
+
+// the robots after secs seconds as '#', x runs along the columns and y down the rows
+pub fn robots_grid(bathroom: &Input, secs: isize) -> CharGrid {
+    let space = &bathroom.space;
+    let mut grid = CharGrid::new(space.1, space.0, '.');
+    for (pos, vel) in &bathroom.robots {
+        let pos = move_robot(pos, vel, &secs, space);
+        grid[BoundVector2D::new(pos.y, pos.x, space.1, space.0).unwrap()] = '#';
+    }
+    grid
+}
+
+fn parse_pos_and_vel(line: Line) -> Result<(Vector, Vector), ParseError> {
+    let pos: (isize, isize) = line.pair(line.value("p")?, ',')?;
+    let vel: (isize, isize) = line.pair(line.value("v")?, ',')?;
+    Ok((pos.into(), vel.into()))
+}
+
+fn move_robot(pos: &Vector, vel: &Vector, times: &isize, space: &(usize, usize)) -> BoundVector2D {
+    (*pos + *vel * *times).wrap(space.0, space.1).unwrap()
+}
+
+fn get_quadrant(pos: &BoundVector2D, space: &(usize, usize)) -> usize {
+    let mid = (space.0 / 2, space.1 / 2);
+    if pos.x < mid.0 && pos.y < mid.1 {
+        1 // Top-left
+    } else if pos.x > mid.0 && pos.y < mid.1 {
+        2 // Top-right
+    } else if pos.x < mid.0 && pos.y > mid.1 {
+        3 // Bottom-left
+    } else if pos.x > mid.0 && pos.y > mid.1 {
+        4 // Bottom-right
+    } else {
+        0
+    }
+}
+
+aoc_core::fixture_tests!(Puzzle14);
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::{answers::assert_answers, location::Location};
+
+    use super::*;
+
+    #[test]
+    fn test_quadrant() {
+        let result = get_quadrant(&BoundVector2D::new(1, 3, 3, 7).unwrap(), &(3, 7));
+        assert_eq!(result, 0);
+    }
+
+    #[test]
+    fn test_move_robot() {
+        let result = move_robot(&Vector::new(2, 4), &Vector::new(2, -3), &5, &(11, 7));
+        assert_eq!((result.x, result.y), (1, 3));
+    }
+
+    #[test]
+    fn test_christmas_tree_touches_across_edges() {
+        let robots = HashSet::from([
+            BoundVector2D::new(0, 0, 11, 7).unwrap(),
+            BoundVector2D::new(10, 0, 11, 7).unwrap(),
+            BoundVector2D::new(5, 3, 11, 7).unwrap(),
+        ]);
+        assert!(is_christmas_tree(&robots, &(11, 7)));
+        let robots = HashSet::from([
+            BoundVector2D::new(0, 0, 11, 7).unwrap(),
+            BoundVector2D::new(9, 0, 11, 7).unwrap(),
+        ]);
+        assert!(!is_christmas_tree(&robots, &(11, 7)));
+    }
+
+    #[test]
+    fn test_min_secs_to_christmas_tree() {
+        let robots = [
+            (Vector::new(0, 0), Vector::new(1, 0)),
+            (Vector::new(2, 0), Vector::new(0, 0)),
+        ];
+        assert_eq!(min_secs_to_christmas_tree(&robots, &(11, 7)), Some(1));
+    }
+
+    #[test]
+    fn test_no_christmas_tree() {
+        let robots = [
+            (Vector::new(0, 0), Vector::new(0, 0)),
+            (Vector::new(5, 3), Vector::new(0, 0)),
+        ];
+        assert_eq!(min_secs_to_christmas_tree(&robots, &(11, 7)), None);
+    }
+
+    #[test]
+    fn test_robots_grid() {
+        let bathroom = parse("space=11,7\np=2,4 v=2,-3\np=0,0 v=1,1\n").unwrap();
+        let grid = robots_grid(&bathroom, 5);
+        assert_eq!((grid.rows, grid.cols), (7, 11));
+        assert_eq!(grid[BoundVector2D::new(3, 1, 7, 11).unwrap()], '#');
+        assert_eq!(grid[BoundVector2D::new(5, 5, 7, 11).unwrap()], '#');
+        assert_eq!(grid.iter().filter(|(_, &c)| c == '#').count(), 2);
+    }
+
+    #[test]
+    fn test_parse_space() {
+        let bathroom = parse("space=11,7\np=0,4 v=3,-3\n").unwrap();
+        assert_eq!(bathroom.space, (11, 7));
+        assert_eq!(
+            bathroom.robots,
+            vec![(Vector::new(0, 4), Vector::new(3, -3))]
+        );
+        assert_eq!(parse("p=0,4 v=3,-3\n").unwrap().space, SPACE);
+    }
+
+    #[test]
+    fn test_parse_invalid_space() {
+        let result = parse("space=11\np=0,4 v=3,-3\n");
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "line 1, column 9: expected ','"
+        );
+    }
+
+    #[test]
+    fn test_parse_pos_and_vel() {
+        let result = parse_pos_and_vel(Line::new(0, "p=0,4 v=3,-3"));
+        assert_eq!(result, Ok((Vector::new(0, 4), Vector::new(3, -3))));
+    }
+
+    #[test]
+    fn test_parse_pos_and_vel_missing_velocity() {
+        let result = parse_pos_and_vel(Line::new(2, "p=0,4"));
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "line 3, column 6: expected \"v=\""
+        );
+    }
+
+    #[test]
+    fn test_parse_pos_and_vel_missing_comma() {
+        let result = parse_pos_and_vel(Line::new(0, "p=0,4 v=3"));
+        assert_eq!(
+            result,
+            Err(ParseError::Expected {
+                location: Location::new(1, 10),
+                expected: "','".to_owned()
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_pos_and_vel_invalid_number() {
+        let result = parse_pos_and_vel(Line::new(0, "p=0,4 v=3,-x"));
+        assert_eq!(
+            result,
+            Err(ParseError::InvalidNumber {
+                location: Location::new(1, 11),
+                token: "-x".to_owned()
+            })
+        );
+    }
+
+    #[test]
+    fn test_main() {
+        assert_answers::<Puzzle14>();
+    }
+}